@@ -0,0 +1,280 @@
+use std::time::{Duration, Instant};
+
+use rand::{Rng, SeedableRng};
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::base_state::State;
+use crate::core::game_state::GameState;
+use crate::core::player_action::PlayerAction;
+use crate::sim::policy::{GreedyPolicy, PlayerPolicy};
+
+/// Safety valve for rollouts, matching the simulator's per-turn limit.
+const MAX_ROLLOUT_DECISIONS: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct MctsConfig {
+    /// Stop after this many playouts...
+    pub iterations: usize,
+    /// ...or once this much time has passed, whichever comes first.
+    pub time_budget: Duration,
+    pub exploration: f64,
+    /// Whole turns played out by the rollout policy after the searched turn ends.
+    pub rollout_turns: usize,
+    pub seed: u64,
+}
+
+impl Default for MctsConfig {
+    fn default() -> Self {
+        MctsConfig {
+            iterations: 2000,
+            time_budget: Duration::from_millis(300),
+            exploration: std::f64::consts::SQRT_2,
+            rollout_turns: 3,
+            seed: 0,
+        }
+    }
+}
+
+struct Node {
+    action: Option<PlayerAction>,
+    visits: u32,
+    total_value: f64,
+    children: Vec<usize>,
+}
+
+impl Node {
+    fn new(action: Option<PlayerAction>) -> Self {
+        Node { action, visits: 0, total_value: 0.0, children: Vec::new() }
+    }
+    
+    fn ucb(&self, parent_visits: u32, exploration: f64) -> f64 {
+        if self.visits == 0 {
+            return f64::INFINITY;
+        }
+        let mean = self.total_value / self.visits as f64;
+        mean + exploration * ((parent_visits as f64).ln() / self.visits as f64).sqrt()
+    }
+}
+
+/// Scores a position from the player's point of view, between 0 (dead) and 1.
+/// Wins always score above any unfinished position.
+pub fn evaluate(state: &GameState) -> f64 {
+    let player = state.player();
+    if !player.is_alive() {
+        return 0.0;
+    }
+    
+    let player_health = player.get_current_health() as f64 / player.get_max_health().max(1) as f64;
+    if state.are_all_enemies_dead() {
+        return 0.6 + 0.4 * player_health;
+    }
+    
+    let (enemy_health, enemy_max) = state.enemies()
+        .iter()
+        .chain(state.graveyard().iter())
+        .fold((0, 0), |(health, max), (_, enemy)| {
+            (health + enemy.get_current_health().max(0), max + enemy.get_max_health())
+        });
+    let enemy_damage = 1.0 - enemy_health as f64 / enemy_max.max(1) as f64;
+    
+    0.6 * (0.5 * player_health + 0.5 * enemy_damage)
+}
+
+fn rollout(state: &mut GameState, turn_ended: bool, turns: usize) {
+    let mut policy = GreedyPolicy;
+    let mut remaining_turns = if turn_ended { turns } else { turns + 1 };
+    let mut decisions = 0;
+    
+    while remaining_turns > 0 && !state.is_combat_over() {
+        let mut action = if decisions < MAX_ROLLOUT_DECISIONS {
+            policy.decide(state)
+        } else {
+            PlayerAction::EndTurn
+        };
+        if state.validate_action(&action).is_err() {
+            action = PlayerAction::EndTurn;
+        }
+        
+        let _ = state.apply(action);
+        decisions += 1;
+        
+        if action == PlayerAction::EndTurn {
+            remaining_turns -= 1;
+            decisions = 0;
+        }
+    }
+}
+
+/// Plans the rest of the player's turn with open-loop Monte Carlo tree search.
+///
+/// Each playout samples the hidden information (draw order and future random
+/// rolls) afresh, so the plan does not rely on knowing the deck order. The
+/// returned actions are meant to be applied in order; the plan ends with
+/// `EndTurn` unless the search ran out of budget before reaching it.
+pub fn suggest_turn(state: &GameState, config: &MctsConfig) -> Vec<PlayerAction> {
+    if state.is_combat_over() {
+        return Vec::new();
+    }
+    
+    let mut search = MctsSearch::new(state, config);
+    search.run_for(config.time_budget);
+    search.plan()
+}
+
+/// The search behind `suggest_turn`, for callers that cannot wait for all of
+/// it at once, such as the UI running a slice each frame. Running it in slices
+/// gives the same plan as running it in one go, as long as the time budget is
+/// not what stops it.
+pub struct MctsSearch {
+    root: GameState,
+    config: MctsConfig,
+    rng: ChaCha8Rng,
+    nodes: Vec<Node>,
+    iterations: usize,
+    elapsed: Duration,
+}
+
+impl MctsSearch {
+    pub fn new(state: &GameState, config: &MctsConfig) -> Self {
+        let mut root = state.clone();
+        root.stop_recording();
+        
+        MctsSearch {
+            root,
+            config: config.clone(),
+            rng: ChaCha8Rng::seed_from_u64(config.seed),
+            nodes: vec![Node::new(None)],
+            iterations: 0,
+            elapsed: Duration::ZERO,
+        }
+    }
+    
+    /// Whether the search has used up its iterations or its time budget.
+    pub fn is_finished(&self) -> bool {
+        self.root.is_combat_over()
+            || self.iterations >= self.config.iterations
+            || self.elapsed >= self.config.time_budget
+    }
+    
+    /// Runs playouts for at most `slice`, then returns whether the search is finished.
+    pub fn run_for(&mut self, slice: Duration) -> bool {
+        let started = Instant::now();
+        let spent_before = self.elapsed;
+        while !self.is_finished() && started.elapsed() < slice {
+            self.playout();
+            self.iterations += 1;
+            self.elapsed = spent_before + started.elapsed();
+        }
+        self.is_finished()
+    }
+    
+    fn playout(&mut self) {
+        let nodes = &mut self.nodes;
+        let rng = &mut self.rng;
+        let config = &self.config;
+        
+        let mut playout = self.root.clone();
+        playout.determinize(rng.r#gen());
+        
+        let mut path = vec![0];
+        let mut turn_ended = false;
+        
+        loop {
+            let current = *path.last().unwrap();
+            if turn_ended || playout.is_combat_over() {
+                break;
+            }
+            
+            let legal = playout.legal_actions();
+            let untried: Vec<PlayerAction> = legal
+                .iter()
+                .copied()
+                .filter(|action| !nodes[current].children.iter().any(|&c| nodes[c].action == Some(*action)))
+                .collect();
+            
+            let (child, action) = if let Some(&action) = untried.choose(rng) {
+                let child = nodes.len();
+                nodes.push(Node::new(Some(action)));
+                nodes[current].children.push(child);
+                (child, action)
+            } else {
+                let parent_visits = nodes[current].visits;
+                let best = nodes[current].children
+                    .iter()
+                    .copied()
+                    .filter(|&c| nodes[c].action.is_some_and(|a| legal.contains(&a)))
+                    .max_by(|&a, &b| {
+                        nodes[a].ucb(parent_visits, config.exploration)
+                            .total_cmp(&nodes[b].ucb(parent_visits, config.exploration))
+                    });
+                match best {
+                    Some(child) => (child, nodes[child].action.unwrap()),
+                    None => break,
+                }
+            };
+            
+            let expanded = nodes[child].visits == 0;
+            let _ = playout.apply(action);
+            turn_ended = action == PlayerAction::EndTurn;
+            path.push(child);
+            
+            if expanded {
+                break;
+            }
+        }
+        
+        rollout(&mut playout, turn_ended, config.rollout_turns);
+        let value = evaluate(&playout);
+        
+        for &index in &path {
+            nodes[index].visits += 1;
+            nodes[index].total_value += value;
+        }
+    }
+    
+    /// The most visited line so far.
+    pub fn plan(&self) -> Vec<PlayerAction> {
+        let nodes = &self.nodes;
+        let mut plan = Vec::new();
+        let mut current = 0;
+        while let Some(&best) = nodes[current].children.iter().max_by_key(|&&c| nodes[c].visits) {
+            let action = nodes[best].action.unwrap();
+            plan.push(action);
+            if action == PlayerAction::EndTurn {
+                break;
+            }
+            current = best;
+        }
+        
+        if plan.is_empty() {
+            plan.push(PlayerAction::EndTurn);
+        }
+        plan
+    }
+}
+
+/// Simulator policy that searches before every decision and plays the first step.
+pub struct MctsPolicy {
+    config: MctsConfig,
+}
+
+impl MctsPolicy {
+    pub fn new(config: MctsConfig) -> Self {
+        MctsPolicy { config }
+    }
+}
+
+impl PlayerPolicy for MctsPolicy {
+    fn name(&self) -> &str {
+        "mcts"
+    }
+    
+    fn decide(&mut self, state: &GameState) -> PlayerAction {
+        self.config.seed = self.config.seed.wrapping_add(1);
+        suggest_turn(state, &self.config)
+            .first()
+            .copied()
+            .unwrap_or(PlayerAction::EndTurn)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod mcts;
+
+pub use mcts::{MctsConfig, MctsPolicy, MctsSearch, suggest_turn, evaluate};
@@ -0,0 +1,98 @@
+//! Headless combat simulator for balance testing.
+//!
+//! Run from the crate root so card and enemy data can be found:
+//!
+//! ```text
+//! cargo run --bin simulate -- --deck strike,strike,defend+ --encounter dragonling,dragonling --seed 7 --combats 500 --policy greedy
+//! ```
+
+use std::env;
+use std::process;
+
+use SlayTheSpire::cards;
+use SlayTheSpire::save::default_registry;
+use SlayTheSpire::sim::{self, SimulationConfig, POLICY_NAMES};
+
+const USAGE: &str = "usage: simulate [--deck a,b,c+] [--encounter enemy,enemy] [--seed N] \
+[--combats N] [--policy NAME] [--hp N] [--max-turns N]";
+
+fn parse_number<T: std::str::FromStr>(flag: &str, value: Option<String>) -> Result<T, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+    value.parse().map_err(|_| format!("Invalid value for {}: {}", flag, value))
+}
+
+fn parse_args() -> Result<(SimulationConfig, String), String> {
+    let mut config = SimulationConfig {
+        deck: cards::starting_deck().iter().map(|card| card.id().to_string()).collect(),
+        ..SimulationConfig::default()
+    };
+    let mut policy = "greedy".to_string();
+    
+    let mut args = env::args().skip(1);
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "--deck" => {
+                let list = args.next().ok_or("--deck needs a value")?;
+                config.deck = sim::parse_deck_list(&list);
+            }
+            "--encounter" => {
+                let list = args.next().ok_or("--encounter needs a value")?;
+                config.encounter = sim::parse_deck_list(&list);
+            }
+            "--seed" => config.seed = parse_number(&flag, args.next())?,
+            "--combats" => config.combats = parse_number(&flag, args.next())?,
+            "--hp" => config.player_health = parse_number(&flag, args.next())?,
+            "--max-turns" => config.max_turns = parse_number(&flag, args.next())?,
+            "--policy" => policy = args.next().ok_or("--policy needs a value")?,
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                process::exit(0);
+            }
+            other => return Err(format!("Unknown argument: {}", other)),
+        }
+    }
+    
+    if !POLICY_NAMES.contains(&policy.as_str()) {
+        return Err(format!("Unknown policy '{}', expected one of: {}", policy, POLICY_NAMES.join(", ")));
+    }
+    
+    Ok((config, policy))
+}
+
+fn main() {
+    let (config, policy) = match parse_args() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("{}\n{}", e, USAGE);
+            process::exit(2);
+        }
+    };
+    
+    let registry = default_registry();
+    let report = sim::run_simulation(&registry, &config, |seed| {
+        sim::policy_by_name(&policy, seed).expect("policy name was validated")
+    });
+    
+    let report = match report {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("Simulation failed: {}", e);
+            process::exit(1);
+        }
+    };
+    
+    println!("Deck:       {}", config.deck.join(", "));
+    println!("Encounter:  {}", config.encounter.join(", "));
+    println!("Policy:     {}", policy);
+    println!("Seed:       {}", config.seed);
+    println!("Combats:    {}", report.combats);
+    println!();
+    println!("Win rate:        {:.1}%", report.win_rate() * 100.0);
+    println!("Avg HP lost:     {:.2}", report.average_hp_lost());
+    println!("Avg turns:       {:.2}", report.average_turns());
+    println!();
+    println!("Damage per card (avg per combat):");
+    for (card_id, damage) in report.damage_ranking() {
+        println!("  {:<16} {:>8.2}", card_id, damage as f64 / report.combats.max(1) as f64);
+    }
+}
@@ -12,7 +12,7 @@ impl Action for AddModifierAction {
         match source {
             EntityId::Player => game_state.player_mut().add_modifier(self.modifier.clone()),
             EntityId::Enemy(id) => {
-                if let Some(enemy) = game_state.enemies_mut().get_mut(id) {
+                if let Some(enemy) = game_state.enemy_mut(id) {
                     enemy.add_modifier(self.modifier.clone());
                 }
             }
@@ -1,6 +1,6 @@
 use crate::core::action::Action;
 use crate::core::game_state::{GameState, EntityId};
-use crate::core::base_state::{StatusType, State};
+use crate::core::base_state::StatusType;
 
 #[derive(Debug, Clone)]
 pub struct ApplyStatusAction {
@@ -17,21 +17,12 @@ impl Action for ApplyStatusAction {
         _energy_spent: Option<i32>,
     ) {
         for &target in targets {
-            match target {
-                EntityId::Player => {
-                    game_state.player_mut().add_status(self.status_type.clone(), self.stacks);
-                }
-                EntityId::Enemy(id) => {
-                    if let Some(enemy) = game_state.enemies_mut().get_mut(id) {
-                        enemy.add_status(self.status_type.clone(), self.stacks);
-                    }
-                }
-            }
+            game_state.add_status(target, self.status_type.clone(), self.stacks);
         }
     }
     
     fn description(&self) -> String {
-        format!("Apply {} {:?}", self.stacks, self.status_type)
+        format!("Apply {} {}", self.stacks, self.status_type.id())
     }
 
     fn clone_box(&self) -> Box<dyn Action> {
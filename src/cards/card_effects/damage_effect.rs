@@ -19,6 +19,10 @@ impl Action for DamageEffect {
         format!("Deal {} damage", self.amount)
     }
     
+    fn intent_damage(&self) -> Option<i32> {
+        Some(self.amount)
+    }
+    
     fn clone_box(&self) -> Box<dyn Action> {
         Box::new(self.clone())
     }
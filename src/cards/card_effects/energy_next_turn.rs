@@ -1,7 +1,8 @@
 use crate::core::effects::{Effect, EffectUIState};
 use crate::core::game_state::{GameState, GameEvent, EntityId};
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EnergyNextTurnEffect {
     pub amount: i32,
     activated: bool,
@@ -18,13 +19,14 @@ impl EnergyNextTurnEffect {
 
 impl Effect for EnergyNextTurnEffect {
     fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
-        if let GameEvent::TurnStarted { entity } = event {
-            if *entity == owner {
-                if let EntityId::Player = owner {
-                    game_state.player_mut().gain_energy(self.amount);
-                    self.activated = true;
-                }
+        if let GameEvent::TurnStarted { entity } = event
+            && *entity == owner
+        {
+            // Only the player has energy; on anyone else this simply expires.
+            if owner == EntityId::Player {
+                game_state.gain_energy(self.amount);
             }
+            self.activated = true;
         }
     }
     
@@ -43,4 +45,12 @@ impl Effect for EnergyNextTurnEffect {
     fn clone_box(&self) -> Box<dyn Effect> {
         Box::new(self.clone())
     }
+    
+    fn id(&self) -> &'static str {
+        "energy_next_turn"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("EnergyNextTurnEffect is always serializable")
+    }
 }
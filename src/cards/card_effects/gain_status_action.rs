@@ -0,0 +1,30 @@
+use crate::core::action::Action;
+use crate::core::game_state::{GameState, EntityId};
+use crate::core::base_state::StatusType;
+
+/// Like `ApplyStatusAction`, but on whoever performs it.
+#[derive(Debug, Clone)]
+pub struct GainStatusAction {
+    pub status_type: StatusType,
+    pub stacks: i32,
+}
+
+impl Action for GainStatusAction {
+    fn resolve(
+        &self,
+        game_state: &mut GameState,
+        source: EntityId,
+        _targets: &[EntityId],
+        _energy_spent: Option<i32>,
+    ) {
+        game_state.add_status(source, self.status_type.clone(), self.stacks);
+    }
+    
+    fn description(&self) -> String {
+        format!("Gain {} {}", self.stacks, self.status_type.id())
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
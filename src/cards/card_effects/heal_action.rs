@@ -0,0 +1,21 @@
+use crate::core::action::Action;
+use crate::core::game_state::{GameState, EntityId};
+
+#[derive(Clone, Debug)]
+pub struct HealAction {
+    pub amount: i32,
+}
+
+impl Action for HealAction {
+    fn resolve(&self, game_state: &mut GameState, source: EntityId, _targets: &[EntityId], _energy_spent: Option<i32>) {
+        game_state.heal(source, self.amount);
+    }
+    
+    fn description(&self) -> String {
+        format!("Heal {} HP", self.amount)
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
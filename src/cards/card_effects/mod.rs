@@ -7,6 +7,11 @@ pub mod draw_cards_action;
 pub mod apply_status_action;
 pub mod x_damage_effect;
 pub mod energy_next_turn;
+pub mod shackle_action;
+pub mod heal_action;
+pub mod temp_hp_action;
+pub mod stun_action;
+pub mod gain_status_action;
 
 pub use damage_effect::DamageEffect;
 pub use block_effect::BlockEffect;
@@ -17,3 +22,8 @@ pub use draw_cards_action::DrawCardsAction;
 pub use apply_status_action::ApplyStatusAction;
 pub use x_damage_effect::XDamageEffect;
 pub use energy_next_turn::EnergyNextTurnEffect;
+pub use shackle_action::ShackleAction;
+pub use heal_action::HealAction;
+pub use temp_hp_action::TempHpAction;
+pub use stun_action::StunAction;
+pub use gain_status_action::GainStatusAction;
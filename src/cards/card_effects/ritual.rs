@@ -1,19 +1,25 @@
 use crate::core::effects::{Effect, EffectUIState};
 use crate::core::game_state::{GameState, GameEvent, EntityId};
-use crate::core::base_state::{StatusType, State};
+use crate::core::base_state::StatusType;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ritual {
     pub amount: i32,
+    /// Enemies gain Ritual during their own turn and only start growing on the next one.
+    #[serde(default)]
+    pub skip_first: bool,
 }
 
 impl Effect for Ritual {
     fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
-        if let GameEvent::TurnEnded { entity } = event {
-            if *entity == owner {
-                if let EntityId::Player = owner {
-                    game_state.player_mut().add_status(StatusType::Strength, self.amount);
-                }
+        if let GameEvent::TurnEnded { entity } = event
+            && *entity == owner
+        {
+            if self.skip_first {
+                self.skip_first = false;
+            } else {
+                game_state.add_status(owner, StatusType::STRENGTH, self.amount);
             }
         }
     }
@@ -21,7 +27,7 @@ impl Effect for Ritual {
     fn ui_state(&self) -> EffectUIState {
         EffectUIState {
             name: format!("Ritual"),
-            description: format!("At the end of your turn, gain {} Strength.", self.amount),
+            description: format!("At the end of each turn, gains {} Strength.", self.amount),
             counters: vec![],
         }
     }
@@ -29,4 +35,12 @@ impl Effect for Ritual {
     fn clone_box(&self) -> Box<dyn Effect> {
         Box::new(self.clone())
     }
+    
+    fn id(&self) -> &'static str {
+        "ritual"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Ritual is always serializable")
+    }
 }
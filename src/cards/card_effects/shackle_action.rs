@@ -0,0 +1,38 @@
+use crate::core::action::Action;
+use crate::core::game_state::{GameState, EntityId};
+use crate::core::base_state::StatusType;
+
+/// Targets lose Strength until the end of their next turn.
+#[derive(Debug, Clone)]
+pub struct ShackleAction {
+    pub amount: i32,
+}
+
+impl Action for ShackleAction {
+    fn resolve(
+        &self,
+        game_state: &mut GameState,
+        _source: EntityId,
+        targets: &[EntityId],
+        _energy_spent: Option<i32>,
+    ) {
+        for &target in targets {
+            let before = game_state.get_status(target, StatusType::STRENGTH);
+            game_state.add_status(target, StatusType::STRENGTH, -self.amount);
+            
+            // Artifact may have negated the loss, in which case there is nothing to give back.
+            let lost = before - game_state.get_status(target, StatusType::STRENGTH);
+            if lost > 0 {
+                game_state.add_status(target, StatusType::SHACKLED, lost);
+            }
+        }
+    }
+    
+    fn description(&self) -> String {
+        format!("Lose {} Strength this turn", self.amount)
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
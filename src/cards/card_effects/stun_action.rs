@@ -0,0 +1,30 @@
+use crate::core::action::{Action, Intent};
+use crate::core::game_state::{GameState, EntityId};
+
+/// Replaces each target enemy's decided move with one that does nothing.
+#[derive(Debug, Clone)]
+pub struct StunAction;
+
+impl Action for StunAction {
+    fn resolve(
+        &self,
+        game_state: &mut GameState,
+        _source: EntityId,
+        targets: &[EntityId],
+        _energy_spent: Option<i32>,
+    ) {
+        for &target in targets {
+            if let EntityId::Enemy(enemy_id) = target {
+                game_state.set_enemy_intent(enemy_id, Intent::stunned());
+            }
+        }
+    }
+    
+    fn description(&self) -> String {
+        "Stun".to_string()
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
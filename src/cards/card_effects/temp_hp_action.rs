@@ -0,0 +1,21 @@
+use crate::core::action::Action;
+use crate::core::game_state::{GameState, EntityId};
+
+#[derive(Clone, Debug)]
+pub struct TempHpAction {
+    pub amount: i32,
+}
+
+impl Action for TempHpAction {
+    fn resolve(&self, game_state: &mut GameState, source: EntityId, _targets: &[EntityId], _energy_spent: Option<i32>) {
+        game_state.gain_temp_hp(source, self.amount);
+    }
+    
+    fn description(&self) -> String {
+        format!("Gain {} Temporary HP", self.amount)
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
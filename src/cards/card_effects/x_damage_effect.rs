@@ -1,5 +1,6 @@
 use crate::core::action::Action;
 use crate::core::game_state::{GameState, EntityId};
+use crate::cards::card_effects::DamageEffect;
 
 #[derive(Debug, Clone)]
 pub struct XDamageEffect {
@@ -15,10 +16,12 @@ impl Action for XDamageEffect {
         energy_spent: Option<i32>,
     ) {
         let x = energy_spent.unwrap_or(0);
-        let total_damage = self.damage_per_energy * x;
         
-        for &target in targets {
-            game_state.deal_damage(source, target, total_damage);
+        // Each hit is its own step, so Strength applies per hit and a target
+        // that dies part way through stops being hit.
+        for _ in 0..x {
+            let hit = DamageEffect { amount: self.damage_per_energy };
+            game_state.queue_action_top(Box::new(hit), source, targets, None);
         }
     }
     
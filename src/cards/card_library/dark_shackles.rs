@@ -0,0 +1,43 @@
+use crate::core::card::{Card, CardType, Cost, CardTargeting};
+use crate::cards::card_effects::ShackleAction;
+use serde::Deserialize;
+use std::fs;
+
+#[derive(Deserialize)]
+struct DarkShacklesConfig {
+    name: String,
+    cost: i32,
+    strength_loss: i32,
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct DarkShacklesFullConfig {
+    regular: DarkShacklesConfig,
+    upgraded: DarkShacklesConfig,
+}
+
+pub fn dark_shackles(instance_id: u32, upgraded: bool) -> Card {
+    let config_str = fs::read_to_string("assets/cards/data/dark_shackles.json")
+        .expect("Failed to read dark_shackles.json");
+    let full_config: DarkShacklesFullConfig = serde_json::from_str(&config_str).unwrap();
+    let config = if upgraded { full_config.upgraded } else { full_config.regular };
+    
+    Card::new(
+        instance_id,
+        "dark_shackles".to_string(),
+        config.name,
+        Cost::Fixed(config.cost),
+        CardType::Skill,
+        CardTargeting::SingleEnemy,
+        vec![Box::new(ShackleAction { amount: config.strength_loss })],
+        config.description.replace("{}", &config.strength_loss.to_string()),
+        upgraded,
+        upgrade,
+        true,
+    )
+}
+
+pub fn upgrade(instance_id: u32) -> Card {
+    dark_shackles(instance_id, true)
+}
@@ -0,0 +1,47 @@
+use crate::core::card::{Card, CardType, Cost, CardTargeting};
+use crate::cards::card_effects::ApplyStatusAction;
+use crate::core::base_state::StatusType;
+use serde::Deserialize;
+use std::fs;
+
+#[derive(Deserialize)]
+struct DisarmConfig {
+    name: String,
+    cost: i32,
+    strength_loss: i32,
+    description: String,
+}
+
+#[derive(Deserialize)]
+struct DisarmFullConfig {
+    regular: DisarmConfig,
+    upgraded: DisarmConfig,
+}
+
+pub fn disarm(instance_id: u32, upgraded: bool) -> Card {
+    let config_str = fs::read_to_string("assets/cards/data/disarm.json")
+        .expect("Failed to read disarm.json");
+    let full_config: DisarmFullConfig = serde_json::from_str(&config_str).unwrap();
+    let config = if upgraded { full_config.upgraded } else { full_config.regular };
+    
+    Card::new(
+        instance_id,
+        "disarm".to_string(),
+        config.name,
+        Cost::Fixed(config.cost),
+        CardType::Skill,
+        CardTargeting::SingleEnemy,
+        vec![Box::new(ApplyStatusAction {
+            status_type: StatusType::STRENGTH,
+            stacks: -config.strength_loss,
+        })],
+        config.description.replace("{}", &config.strength_loss.to_string()),
+        upgraded,
+        upgrade,
+        true,
+    )
+}
+
+pub fn upgrade(instance_id: u32) -> Card {
+    disarm(instance_id, true)
+}
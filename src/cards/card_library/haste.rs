@@ -35,7 +35,7 @@ pub fn haste(instance_id: u32, upgraded: bool) -> Card {
         vec![
             Box::new(DrawCardsAction { count: config.draw }),
             Box::new(ApplyStatusAction { 
-                status_type: StatusType::Vulnerable, 
+                status_type: StatusType::VULNERABLE, 
                 stacks: config.vulnerable 
             }),
         ],
@@ -32,7 +32,7 @@ pub fn inflame(instance_id: u32, upgraded: bool) -> Card {
         CardType::Power,
         CardTargeting::Self_,
         vec![Box::new(ApplyStatusAction {
-            status_type: StatusType::Strength,
+            status_type: StatusType::STRENGTH,
             stacks: config.strength,
         })],
         config.description.replace("{}", &config.strength.to_string()),
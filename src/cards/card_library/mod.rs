@@ -5,6 +5,8 @@ pub mod barricade;
 pub mod whirlwind;
 pub mod haste;
 pub mod quick_strike;
+pub mod disarm;
+pub mod dark_shackles;
 
 pub use strike::strike;
 pub use defend::defend;
@@ -13,3 +15,5 @@ pub use barricade::barricade;
 pub use whirlwind::whirlwind;
 pub use haste::haste;
 pub use quick_strike::quick_strike;
+pub use disarm::disarm;
+pub use dark_shackles::dark_shackles;
@@ -1,8 +1,12 @@
 pub mod card_effects;
 pub mod card_library;
 pub mod upgrade;
+pub mod registry;
+pub mod starting_deck;
 
 pub use card_effects::{DamageEffect, BlockEffect, AddModifierAction, ApplyEffect, Ritual};
-pub use card_library::{strike, defend, inflame, barricade, whirlwind, haste, quick_strike};
+pub use card_library::{strike, defend, inflame, barricade, whirlwind, haste, quick_strike, disarm, dark_shackles};
 pub use upgrade::upgrade_card;
+pub use registry::{register_cards, register_effects};
+pub use starting_deck::starting_deck;
 pub use crate::core::card::CardType;
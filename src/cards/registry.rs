@@ -0,0 +1,20 @@
+use crate::core::registry::{Registry, load_effect};
+use crate::cards::card_library::{strike, defend, inflame, barricade, whirlwind, haste, quick_strike, disarm, dark_shackles};
+use crate::cards::card_effects::{Ritual, EnergyNextTurnEffect};
+
+pub fn register_cards(registry: &mut Registry) {
+    registry.register_card("strike", strike);
+    registry.register_card("defend", defend);
+    registry.register_card("inflame", inflame);
+    registry.register_card("barricade", barricade);
+    registry.register_card("whirlwind", whirlwind);
+    registry.register_card("haste", haste);
+    registry.register_card("quick_strike", quick_strike);
+    registry.register_card("disarm", disarm);
+    registry.register_card("dark_shackles", dark_shackles);
+}
+
+pub fn register_effects(registry: &mut Registry) {
+    registry.register_effect("ritual", load_effect::<Ritual>);
+    registry.register_effect("energy_next_turn", load_effect::<EnergyNextTurnEffect>);
+}
@@ -0,0 +1,18 @@
+use crate::core::card::Card;
+use crate::cards::card_library::{strike, defend, inflame, haste, whirlwind, barricade, quick_strike};
+
+/// The deck every new Ironclad run starts with.
+pub fn starting_deck() -> Vec<Card> {
+    vec![
+        strike(1, false),
+        strike(2, false),
+        defend(3, false),
+        defend(4, false),
+        defend(5, false),
+        inflame(6, false),
+        haste(7, false),
+        whirlwind(8, false),
+        barricade(9, false),
+        quick_strike(10, false),
+    ]
+}
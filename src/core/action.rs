@@ -1,30 +1,111 @@
+use serde::{Serialize, Deserialize};
+
+use crate::core::damage::DamageKind;
 use crate::core::game_state::{GameState, EntityId};
 
 pub trait Action: std::fmt::Debug {
+    /// Runs as one step of the game state's action queue. Follow-up work
+    /// belongs on the queue (`queue_action_top` / `queue_action_bottom`)
+    /// rather than being resolved inline.
     fn resolve(&self, game_state: &mut GameState, source: EntityId, targets: &[EntityId], energy_spent: Option<i32>);
     fn description(&self) -> String;
+    /// Base damage of one hit, for actions that attack. Intent previews use it.
+    fn intent_damage(&self) -> Option<i32> { None }
     fn clone_box(&self) -> Box<dyn Action>;
 }
 
-#[derive(Clone)]
+/// What an enemy is about to do, as shown above its head.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentKind {
+    Attack,
+    Defend,
+    Buff,
+    Debuff,
+    Unknown,
+}
+
+/// An intent as the player sees it: `damage` is one hit after the real
+/// damage calculation, before block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntentPreview {
+    pub kind: IntentKind,
+    pub damage: Option<i32>,
+    pub hits: i32,
+}
+
+#[derive(Clone, Debug)]
 pub struct Intent {
     actions: Vec<Box<dyn Action>>,
     description: String,
+    kind: IntentKind,
+    move_name: Option<String>,
 }
 
 impl Intent {
+    /// Move name of the stunned intent, which every enemy can rebuild from a save.
+    pub const STUNNED: &'static str = "stunned";
+    
+    /// An intent with any attacking action is an attack; otherwise the kind
+    /// is unknown until set with `with_kind`.
     pub fn new(actions: Vec<Box<dyn Action>>, description: String) -> Self {
-        Intent { actions, description }
+        let kind = if actions.iter().any(|action| action.intent_damage().is_some()) {
+            IntentKind::Attack
+        } else {
+            IntentKind::Unknown
+        };
+        Intent { actions, description, kind, move_name: None }
+    }
+    
+    /// Replaces an enemy's decided move with one that does nothing.
+    pub fn stunned() -> Self {
+        Intent::new(Vec::new(), "Stunned".to_string()).with_move(Self::STUNNED)
+    }
+    
+    pub fn with_kind(mut self, kind: IntentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+    
+    /// Names the enemy move this intent comes from, so saves can rebuild it.
+    pub fn with_move(mut self, name: &str) -> Self {
+        self.move_name = Some(name.to_string());
+        self
     }
     
     pub fn description(&self) -> &str {
         &self.description
     }
     
+    pub fn move_name(&self) -> Option<&str> {
+        self.move_name.as_deref()
+    }
+    
+    pub fn kind(&self) -> IntentKind {
+        self.kind
+    }
+    
+    /// Works out per-hit damage against `target` through the same pipeline
+    /// that will resolve the attack.
+    pub fn preview(&self, game_state: &GameState, source: EntityId, target: EntityId) -> IntentPreview {
+        let hits: Vec<i32> = self.actions.iter().filter_map(|action| action.intent_damage()).collect();
+        let damage = hits.first().map(|&base| {
+            game_state.calculate_damage(DamageKind::Attack, source, Some(target), base).damage
+        });
+        
+        IntentPreview {
+            kind: self.kind,
+            damage,
+            hits: hits.len() as i32,
+        }
+    }
+    
+    /// Queues the intent's actions behind anything already pending and resolves the queue.
     pub fn execute(&self, game_state: &mut GameState, source: EntityId, targets: &[EntityId], energy_spent: Option<i32>) {
         for action in &self.actions {
-            action.resolve(game_state, source, targets, energy_spent);
+            game_state.queue_action_bottom(action.clone_box(), source, targets, energy_spent);
         }
+        game_state.resolve_actions();
     }
 }
 
@@ -0,0 +1,50 @@
+use std::collections::VecDeque;
+
+use crate::core::action::Action;
+use crate::core::game_state::EntityId;
+
+/// An action waiting to resolve, together with the context it was queued in.
+#[derive(Clone, Debug)]
+pub struct QueuedAction {
+    pub action: Box<dyn Action>,
+    pub source: EntityId,
+    pub targets: Vec<EntityId>,
+    pub energy_spent: Option<i32>,
+}
+
+/// Actions still to resolve, front first. Resolving an action may push
+/// follow-ups to the top, to run next, or to the bottom, to run last.
+#[derive(Clone, Debug, Default)]
+pub struct ActionQueue {
+    actions: VecDeque<QueuedAction>,
+}
+
+impl ActionQueue {
+    pub fn new() -> Self {
+        ActionQueue::default()
+    }
+    
+    pub fn push_top(&mut self, action: QueuedAction) {
+        self.actions.push_front(action);
+    }
+    
+    pub fn push_bottom(&mut self, action: QueuedAction) {
+        self.actions.push_back(action);
+    }
+    
+    pub fn pop(&mut self) -> Option<QueuedAction> {
+        self.actions.pop_front()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+    
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+    
+    pub fn iter(&self) -> impl Iterator<Item = &QueuedAction> {
+        self.actions.iter()
+    }
+}
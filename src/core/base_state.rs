@@ -1,22 +1,48 @@
+use std::borrow::Cow;
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone, Debug, PartialEq)]
-pub enum StatusType {
-    Strength,
-    Dexterity,
-    Vulnerable,
-    Weak,
-    Poison,
-    Frail,
+/// Id of a status. What a status does is described by its `StatusDefinition`
+/// in the combat's `StatusRegistry`; the built-in ids are the constants below.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct StatusType(Cow<'static, str>);
+
+impl StatusType {
+    pub const STRENGTH: StatusType = StatusType::builtin("Strength");
+    pub const DEXTERITY: StatusType = StatusType::builtin("Dexterity");
+    pub const VULNERABLE: StatusType = StatusType::builtin("Vulnerable");
+    pub const WEAK: StatusType = StatusType::builtin("Weak");
+    pub const POISON: StatusType = StatusType::builtin("Poison");
+    pub const FRAIL: StatusType = StatusType::builtin("Frail");
+    pub const ARTIFACT: StatusType = StatusType::builtin("Artifact");
+    pub const THORNS: StatusType = StatusType::builtin("Thorns");
+    pub const METALLICIZE: StatusType = StatusType::builtin("Metallicize");
+    pub const PLATED_ARMOR: StatusType = StatusType::builtin("PlatedArmor");
+    pub const INTANGIBLE: StatusType = StatusType::builtin("Intangible");
+    pub const ENTANGLED: StatusType = StatusType::builtin("Entangled");
+    pub const SHACKLED: StatusType = StatusType::builtin("Shackled");
+    
+    const fn builtin(id: &'static str) -> Self {
+        StatusType(Cow::Borrowed(id))
+    }
+    
+    pub fn new(id: impl Into<String>) -> Self {
+        StatusType(Cow::Owned(id.into()))
+    }
+    
+    pub fn id(&self) -> &str {
+        &self.0
+    }
 }
 
 /// Enum for game breaking mechanics
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Modifier {
     RetainHand,
     RetainBlock,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Status {
     pub status_type: StatusType,
     pub stacks: i32,
@@ -28,12 +54,15 @@ impl Status {
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseState {
     name: String,
     max_health: i32,
     current_health: i32,
     block: i32,
+    /// Absorbs damage after block and is lost at the end of combat.
+    #[serde(default)]
+    temp_hp: i32,
     statuses: Vec<Status>,
     modifiers: Vec<Modifier>,
 }
@@ -51,11 +80,14 @@ pub trait State {
     fn reduce_status(&mut self, status_type: StatusType, amount: i32);
     fn set_block(&mut self, amount: i32);
     fn set_health(&mut self, amount: i32);
+    /// Never goes below 1; current health is clamped to the new maximum.
+    fn set_max_health(&mut self, amount: i32);
+    fn get_temp_hp(&self) -> i32;
+    fn set_temp_hp(&mut self, amount: i32);
     fn has_modifier(&self, modifier: &Modifier) -> bool;
     fn add_modifier(&mut self, modifier: Modifier);
     fn remove_modifier(&mut self, modifier: &Modifier);
     fn remove_expired_statuses(&mut self);
-    fn decay_debuffs(&mut self);
     fn clear_all_statuses(&mut self);
     fn clear_all_modifiers(&mut self);
 }
@@ -67,6 +99,7 @@ impl BaseState {
             max_health,
             current_health: max_health,
             block: 0,
+            temp_hp: 0,
             statuses: Vec::new(),
             modifiers: Vec::new(),
         }
@@ -116,6 +149,7 @@ impl State for BaseState {
         } else {
             self.statuses.push(Status::new(status_type, stacks));
         }
+        self.remove_expired_statuses();
     }
     
     fn reduce_status(&mut self, status_type: StatusType, amount: i32) {
@@ -133,6 +167,19 @@ impl State for BaseState {
         self.current_health = amount.clamp(0, self.max_health);
     }
     
+    fn set_max_health(&mut self, amount: i32) {
+        self.max_health = amount.max(1);
+        self.current_health = self.current_health.min(self.max_health);
+    }
+    
+    fn get_temp_hp(&self) -> i32 {
+        self.temp_hp
+    }
+    
+    fn set_temp_hp(&mut self, amount: i32) {
+        self.temp_hp = amount.max(0);
+    }
+    
     fn has_modifier(&self, modifier: &Modifier) -> bool {
         self.modifiers.contains(modifier)
     }
@@ -148,19 +195,7 @@ impl State for BaseState {
     }
     
     fn remove_expired_statuses(&mut self) {
-        self.statuses.retain(|s| s.stacks > 0);
-    }
-    
-    fn decay_debuffs(&mut self) {
-        for status in &mut self.statuses {
-            match status.status_type {
-                StatusType::Vulnerable | StatusType::Weak | StatusType::Frail => {
-                    status.stacks -= 1;
-                }
-                _ => {}
-            }
-        }
-        self.remove_expired_statuses();
+        self.statuses.retain(|s| s.stacks != 0);
     }
     
     fn clear_all_statuses(&mut self) {
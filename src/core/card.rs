@@ -1,6 +1,10 @@
+use std::rc::Rc;
+
+use serde::{Serialize, Deserialize};
+
 use crate::core::action::Action;
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CardType {
     Attack,
     Skill,
@@ -23,13 +27,13 @@ pub enum Cost {
     Unplayable,
 }
 
+/// Everything about a card that is fixed once it is built. Copies of a card
+/// share one definition, so cloning a card never clones its effects.
 #[derive(Debug)]
-pub struct Card {
-    instance_id: u32,
+pub struct CardDefinition {
     id: String,
     name: String,
     base_cost: Cost,
-    cost_reduction: i32,
     card_type: CardType,
     targeting: CardTargeting,
     effects: Vec<Box<dyn Action>>,
@@ -39,23 +43,11 @@ pub struct Card {
     exhaust: bool,
 }
 
-impl Clone for Card {
-    fn clone(&self) -> Self {
-        Card {
-            instance_id: self.instance_id,
-            id: self.id.clone(),
-            name: self.name.clone(),
-            base_cost: self.base_cost.clone(),
-            cost_reduction: self.cost_reduction,
-            card_type: self.card_type,
-            targeting: self.targeting,
-            effects: self.effects.iter().map(|e| e.clone_box()).collect(),
-            description: self.description.clone(),
-            upgraded: self.upgraded,
-            upgrade_fn: self.upgrade_fn,
-            exhaust: self.exhaust,
-        }
-    }
+#[derive(Clone, Debug)]
+pub struct Card {
+    instance_id: u32,
+    cost_reduction: i32,
+    definition: Rc<CardDefinition>,
 }
 
 impl Card {
@@ -72,12 +64,10 @@ impl Card {
         upgrade_fn: fn(u32) -> Card,
         exhaust: bool,
     ) -> Self {
-        Card {
-            instance_id,
+        let definition = CardDefinition {
             id,
             name,
             base_cost,
-            cost_reduction: 0,
             card_type,
             targeting,
             effects,
@@ -85,27 +75,40 @@ impl Card {
             upgraded,
             upgrade_fn,
             exhaust,
+        };
+        Card::from_definition(instance_id, Rc::new(definition))
+    }
+    
+    pub fn from_definition(instance_id: u32, definition: Rc<CardDefinition>) -> Self {
+        Card {
+            instance_id,
+            cost_reduction: 0,
+            definition,
         }
     }
+    
+    pub fn definition(&self) -> &Rc<CardDefinition> {
+        &self.definition
+    }
 
     pub fn instance_id(&self) -> u32 {
         self.instance_id
     }
     
     pub fn id(&self) -> &str {
-        &self.id
+        &self.definition.id
     }
     
     pub fn name(&self) -> &str {
-        &self.name
+        &self.definition.name
     }
     
     pub fn base_cost(&self) -> &Cost {
-        &self.base_cost
+        &self.definition.base_cost
     }
     
     pub fn get_current_cost(&self) -> Result<Option<i32>, String> {
-        match &self.base_cost {
+        match &self.definition.base_cost {
             Cost::Fixed(base) => {
                 let cost = (*base - self.cost_reduction).max(0);
                 Ok(Some(cost))
@@ -129,30 +132,30 @@ impl Card {
     }
     
     pub fn card_type(&self) -> &CardType {
-        &self.card_type
+        &self.definition.card_type
     }
     
     pub fn targeting(&self) -> CardTargeting {
-        self.targeting
+        self.definition.targeting
     }
     
     pub fn effects(&self) -> &Vec<Box<dyn Action>> {
-        &self.effects
+        &self.definition.effects
     }
     
     pub fn description(&self) -> &str {
-        &self.description
+        &self.definition.description
     }
     
     pub fn is_upgraded(&self) -> bool {
-        self.upgraded
+        self.definition.upgraded
     }
     
     pub fn upgrade_fn(&self) -> fn(u32) -> Card {
-        self.upgrade_fn
+        self.definition.upgrade_fn
     }
     
     pub fn exhaust(&self) -> bool {
-        self.exhaust
+        self.definition.exhaust
     }
 }
@@ -0,0 +1,132 @@
+use serde::{Serialize, Deserialize};
+
+use crate::core::base_state::{State, StatusType};
+use crate::core::game_state::{EntityId, GameEvent};
+use crate::core::player_action::PlayerAction;
+use crate::core::snapshot::GameStateSnapshot;
+
+/// The values a log entry tracks for one combatant.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EntityState {
+    pub health: i32,
+    pub block: i32,
+    pub statuses: Vec<(StatusType, i32)>,
+}
+
+impl EntityState {
+    pub fn of(entity: &dyn State) -> Self {
+        EntityState {
+            health: entity.get_current_health(),
+            block: entity.get_block(),
+            statuses: entity.get_all_statuses()
+                .iter()
+                .map(|status| (status.status_type.clone(), status.stacks))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EntityChange {
+    pub entity: EntityId,
+    pub before: Option<EntityState>,
+    pub after: Option<EntityState>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum LogRecord {
+    Input(PlayerAction),
+    Event(GameEvent),
+}
+
+/// One line of the log. `changes` lists every combatant whose HP, block or
+/// statuses differ from the previous entry; for inputs it covers the whole
+/// action, including the events logged after it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub turn: usize,
+    pub record: LogRecord,
+    pub changes: Vec<EntityChange>,
+}
+
+pub type EntityStates = Vec<(EntityId, EntityState)>;
+
+fn diff(before: &EntityStates, after: &EntityStates) -> Vec<EntityChange> {
+    let mut entities: Vec<EntityId> = before.iter().chain(after.iter()).map(|(id, _)| *id).collect();
+    entities.sort_by_key(|id| match id {
+        EntityId::Player => None,
+        EntityId::Enemy(enemy_id) => Some(*enemy_id),
+    });
+    entities.dedup();
+    
+    let find = |states: &EntityStates, entity: EntityId| {
+        states.iter().find(|(id, _)| *id == entity).map(|(_, state)| state.clone())
+    };
+    
+    entities
+        .into_iter()
+        .filter_map(|entity| {
+            let before = find(before, entity);
+            let after = find(after, entity);
+            (before != after).then_some(EntityChange { entity, before, after })
+        })
+        .collect()
+}
+
+/// Ordered record of everything that happened in a combat since recording began.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CombatLog {
+    entries: Vec<LogEntry>,
+    #[serde(skip)]
+    last_states: EntityStates,
+    #[serde(skip)]
+    pending_input: Option<(usize, EntityStates)>,
+}
+
+impl CombatLog {
+    pub fn new(states: EntityStates) -> Self {
+        CombatLog { entries: Vec::new(), last_states: states, pending_input: None }
+    }
+    
+    pub fn entries(&self) -> &[LogEntry] {
+        &self.entries
+    }
+    
+    pub fn inputs(&self) -> Vec<PlayerAction> {
+        self.entries
+            .iter()
+            .filter_map(|entry| match &entry.record {
+                LogRecord::Input(action) => Some(*action),
+                LogRecord::Event(_) => None,
+            })
+            .collect()
+    }
+    
+    /// Logs an input before it runs. Its changes are filled in by `finish_input`
+    /// once everything it set off has resolved.
+    pub fn begin_input(&mut self, turn: usize, action: PlayerAction, states: EntityStates) {
+        self.entries.push(LogEntry { turn, record: LogRecord::Input(action), changes: Vec::new() });
+        self.pending_input = Some((self.entries.len() - 1, states.clone()));
+        self.last_states = states;
+    }
+    
+    pub fn finish_input(&mut self, after: EntityStates) {
+        if let Some((index, before)) = self.pending_input.take() {
+            self.entries[index].changes = diff(&before, &after);
+        }
+        self.last_states = after;
+    }
+    
+    pub fn record_event(&mut self, turn: usize, event: &GameEvent, states: EntityStates) {
+        let changes = diff(&self.last_states, &states);
+        self.entries.push(LogEntry { turn, record: LogRecord::Event(event.clone()), changes });
+        self.last_states = states;
+    }
+}
+
+/// A log together with the state it started from, enough to replay the combat.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CombatRecording {
+    pub initial: GameStateSnapshot,
+    pub log: CombatLog,
+}
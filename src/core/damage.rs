@@ -0,0 +1,134 @@
+use serde::{Serialize, Deserialize};
+
+use crate::core::game_state::{GameState, EntityId};
+
+/// Stages of the damage pipeline, in the order they run. Block and HP loss
+/// always come after the last stage and cannot be modified.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DamageStage {
+    Base,
+    AttackerAdditive,
+    AttackerMultiplicative,
+    DefenderMultiplicative,
+    Cap,
+}
+
+impl DamageStage {
+    pub const ALL: [DamageStage; 5] = [
+        DamageStage::Base,
+        DamageStage::AttackerAdditive,
+        DamageStage::AttackerMultiplicative,
+        DamageStage::DefenderMultiplicative,
+        DamageStage::Cap,
+    ];
+}
+
+/// What kind of hit this is, which decides the stages it goes through,
+/// whether block absorbs it and what reacts to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DamageKind {
+    /// Cards and enemy attacks. Every stage applies, block and temporary HP absorb it, and it
+    /// sets off "whenever attacked" reactions such as Thorns.
+    Attack,
+    /// Damage bounced back at an attacker. Block and temporary HP absorb it, but only the base
+    /// and cap stages apply.
+    Thorns,
+    /// Losing HP directly, e.g. paying HP for an ability. Ignores block and temporary HP.
+    HpLoss,
+    /// Poison ticking at the start of a turn. Ignores block and temporary HP.
+    Poison,
+}
+
+impl DamageKind {
+    pub fn uses_stage(self, stage: DamageStage) -> bool {
+        match self {
+            DamageKind::Attack => true,
+            DamageKind::Thorns | DamageKind::HpLoss | DamageKind::Poison => {
+                matches!(stage, DamageStage::Base | DamageStage::Cap)
+            }
+        }
+    }
+    
+    pub fn is_blockable(self) -> bool {
+        matches!(self, DamageKind::Attack | DamageKind::Thorns)
+    }
+}
+
+/// Who is dealing damage to whom. `target` is `None` when previewing a card
+/// before a target has been picked; defender modifiers then do nothing.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DamageContext {
+    pub kind: DamageKind,
+    pub source: EntityId,
+    pub target: Option<EntityId>,
+    pub base: i32,
+}
+
+/// What one hit comes to after every stage, block and all.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DamageResult {
+    pub damage: i32,
+    pub blocked: i32,
+    /// Taken out of temporary HP, after block and before real HP.
+    pub temp_hp_lost: i32,
+    pub hp_loss: i32,
+}
+
+/// A modifier that is not tied to an effect or status, such as a relic.
+pub trait DamageModifier: std::fmt::Debug {
+    fn stage(&self) -> DamageStage;
+    fn modify(&self, context: &DamageContext, game_state: &GameState, damage: f32) -> f32;
+    /// Registry key used to rebuild this modifier from a save.
+    fn id(&self) -> &'static str;
+    fn save_state(&self) -> serde_json::Value;
+}
+
+/// Which side of a hit a status has to be on to change it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DamageHolder {
+    Attacker,
+    Defender,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DamageOperation {
+    /// Adds this much per stack; negative stacks take damage away.
+    AddPerStack(f32),
+    /// Multiplies while the status has any stacks.
+    Multiply(f32),
+    /// Limits damage while the status has any stacks.
+    Cap(i32),
+}
+
+impl DamageOperation {
+    pub fn apply(self, stacks: i32, value: f32) -> f32 {
+        match self {
+            DamageOperation::AddPerStack(amount) => value + amount * stacks as f32,
+            DamageOperation::Multiply(factor) if stacks > 0 => value * factor,
+            DamageOperation::Cap(max) if stacks > 0 => value.min(max as f32),
+            _ => value,
+        }
+    }
+    
+    pub fn is_additive(self) -> bool {
+        matches!(self, DamageOperation::AddPerStack(_))
+    }
+}
+
+/// How a status takes part in the pipeline, as written in its definition.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusDamageModifier {
+    pub stage: DamageStage,
+    pub holder: DamageHolder,
+    pub operation: DamageOperation,
+}
+
+impl StatusDamageModifier {
+    pub fn apply(&self, stacks: i32, damage: f32) -> f32 {
+        self.operation.apply(stacks, damage)
+    }
+}
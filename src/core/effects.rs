@@ -1,4 +1,5 @@
 use crate::core::game_state::{GameState, GameEvent, EntityId};
+use crate::core::damage::{DamageContext, DamageStage};
 
 pub struct EffectUIState {
     pub name: String,
@@ -10,5 +11,18 @@ pub trait Effect: std::fmt::Debug {
     fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState);
     fn ui_state(&self) -> EffectUIState;
     fn should_remove(&self) -> bool { false }
+    /// Effects with a higher priority see each event first. Equal priorities
+    /// run in the order the effects were added.
+    fn priority(&self) -> i32 { 0 }
+    /// Adjusts a hit at one stage of the damage pipeline. `owner` is who has
+    /// this effect, which may be either side of the hit or neither.
+    fn modify_damage(&self, _owner: EntityId, _stage: DamageStage, _context: &DamageContext, damage: f32) -> f32 { damage }
+    /// Adjusts a heal on `target` before it is capped at max HP.
+    fn modify_heal(&self, _owner: EntityId, _target: EntityId, amount: i32) -> i32 { amount }
+    /// Adjusts block `target` is about to gain, after its statuses have.
+    fn modify_block(&self, _owner: EntityId, _target: EntityId, block: f32) -> f32 { block }
     fn clone_box(&self) -> Box<dyn Effect>;
+    /// Registry key used to rebuild this effect from a save.
+    fn id(&self) -> &'static str;
+    fn save_state(&self) -> serde_json::Value;
 }
@@ -1,14 +1,100 @@
 use crate::core::base_state::{BaseState, State, StatusType, Status, Modifier};
 use crate::core::action::Intent;
+use crate::core::effects::Effect;
+use crate::core::game_state::EnemyId;
+use crate::core::rng::GameRng;
+use serde::{Serialize, Deserialize};
+
+/// One combatant as enemy AI sees it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatantView {
+    pub health: i32,
+    pub max_health: i32,
+    pub block: i32,
+    pub statuses: Vec<(StatusType, i32)>,
+}
+
+impl CombatantView {
+    pub fn of(entity: &dyn State) -> Self {
+        CombatantView {
+            health: entity.get_current_health(),
+            max_health: entity.get_max_health(),
+            block: entity.get_block(),
+            statuses: entity.get_all_statuses()
+                .iter()
+                .map(|status| (status.status_type.clone(), status.stacks))
+                .collect(),
+        }
+    }
+    
+    pub fn status(&self, status: &StatusType) -> i32 {
+        self.statuses.iter().find(|(s, _)| s == status).map(|(_, stacks)| *stacks).unwrap_or(0)
+    }
+    
+    pub fn health_percent(&self) -> i32 {
+        self.health * 100 / self.max_health.max(1)
+    }
+}
+
+/// Read-only picture of the combat handed to an enemy when it picks its move.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CombatView {
+    pub turn: usize,
+    /// The enemy doing the choosing.
+    pub viewer: EnemyId,
+    pub player: CombatantView,
+    /// Every living enemy, the viewer included.
+    pub enemies: Vec<(EnemyId, CombatantView)>,
+}
+
+impl CombatView {
+    pub fn enemy(&self, id: EnemyId) -> Option<&CombatantView> {
+        self.enemies.iter().find(|(enemy_id, _)| *enemy_id == id).map(|(_, view)| view)
+    }
+    
+    /// Living enemies other than the viewer.
+    pub fn allies(&self) -> impl Iterator<Item = &(EnemyId, CombatantView)> {
+        self.enemies.iter().filter(move |(id, _)| *id != self.viewer)
+    }
+}
 
 pub trait Enemy: State {
-    fn get_intent(&self, turn_count: usize) -> Intent;
+    /// Decides the next move from a view of the combat. Called once per
+    /// player turn; the result is stored with `set_intent` and executed as shown.
+    fn choose_intent(&mut self, view: &CombatView, rng: &mut GameRng) -> Intent;
+    /// Moves picked so far this combat, oldest first.
+    fn move_history(&self) -> &[String] { &[] }
+    /// The intent for one of its named moves, for powers that force a move.
+    fn move_intent(&self, _name: &str) -> Option<Intent> { None }
+    /// Powers added when it enters the combat.
+    fn starting_powers(&self) -> Vec<Box<dyn Effect>> { Vec::new() }
+    /// Which art to draw it with, if it has any.
+    fn art_key(&self) -> Option<&str> { None }
+    fn intent(&self) -> Option<&Intent>;
+    fn set_intent(&mut self, intent: Option<Intent>);
+    /// Replaces the decided move with one forced on it, such as a stun.
+    fn override_intent(&mut self, intent: Intent) {
+        self.set_intent(Some(intent));
+    }
+    /// Registry key used to rebuild this enemy from a save.
+    fn id(&self) -> &str;
+    fn save_state(&self) -> serde_json::Value;
+    fn clone_box(&self) -> Box<dyn Enemy>;
+}
+
+impl Clone for Box<dyn Enemy> {
+    fn clone(&self) -> Self {
+        self.clone_box()
+    }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseEnemy {
     base_state: BaseState,
     id: String,
+    /// Not saved here; enemies that can rebuild it keep its move name in their own state.
+    #[serde(skip)]
+    intent: Option<Intent>,
 }
 
 impl BaseEnemy {
@@ -16,12 +102,21 @@ impl BaseEnemy {
         BaseEnemy {
             base_state: BaseState::new(name, max_health),
             id,
+            intent: None,
         }
     }
     
     pub fn id(&self) -> &str {
         &self.id
     }
+    
+    pub fn intent(&self) -> Option<&Intent> {
+        self.intent.as_ref()
+    }
+    
+    pub fn set_intent(&mut self, intent: Option<Intent>) {
+        self.intent = intent;
+    }
 }
 
 impl State for BaseEnemy {
@@ -73,6 +168,18 @@ impl State for BaseEnemy {
         self.base_state.set_health(amount)
     }
     
+    fn set_max_health(&mut self, amount: i32) {
+        self.base_state.set_max_health(amount)
+    }
+    
+    fn get_temp_hp(&self) -> i32 {
+        self.base_state.get_temp_hp()
+    }
+    
+    fn set_temp_hp(&mut self, amount: i32) {
+        self.base_state.set_temp_hp(amount)
+    }
+    
     fn has_modifier(&self, modifier: &Modifier) -> bool {
         self.base_state.has_modifier(modifier)
     }
@@ -89,10 +196,6 @@ impl State for BaseEnemy {
         self.base_state.remove_expired_statuses()
     }
     
-    fn decay_debuffs(&mut self) {
-        self.base_state.decay_debuffs()
-    }
-    
     fn clear_all_statuses(&mut self) {
         self.base_state.clear_all_statuses()
     }
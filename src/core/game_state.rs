@@ -1,33 +1,89 @@
 use crate::core::{Player, card::Card};
+use crate::core::pile::Pile;
 use crate::core::base_state::{StatusType, Modifier, State};
-use crate::core::enemy::Enemy;
-use crate::core::action::Intent;
+use crate::core::enemy::{Enemy, CombatView, CombatantView};
+use crate::core::action::{Action, Intent, IntentPreview};
+use crate::core::action_queue::{ActionQueue, QueuedAction};
 use crate::core::effects::Effect;
-use crate::core::card::CardTargeting;
+use crate::core::damage::{DamageContext, DamageHolder, DamageKind, DamageModifier, DamageOperation, DamageResult, DamageStage};
+use crate::core::status::{DecayTiming, Stacking, StatusDefinition, StatusRegistry};
+use crate::core::card::{CardTargeting, CardType};
+use crate::core::rng::{GameRng, RngStream};
+use crate::core::registry::Registry;
+use crate::core::player_action::PlayerAction;
+use crate::core::combat_log::{CombatLog, CombatRecording, EntityState, EntityStates};
+use crate::core::snapshot::{CardSnapshot, DamageModifierSnapshot, EffectSnapshot, EnemySnapshot, GameStateSnapshot, snapshot_cards, restore_cards};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use serde::{Serialize, Deserialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::rc::Rc;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+const HERO_ABILITY_COST: i32 = 1;
+const HERO_ABILITY_DAMAGE: i32 = 2;
+
+/// Most enemies that can be on the field at once; summons and splits past it fail.
+pub const MAX_ENEMIES: usize = 5;
+
+/// Identifier handed out to an enemy when it joins the combat. It is never
+/// reused, so it keeps pointing at the same enemy after others die.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EnemyId(pub usize);
+
+pub type EnemySlot = (EnemyId, Box<dyn Enemy>);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum EntityId {
     Player,
-    Enemy(usize),
+    Enemy(EnemyId),
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum GameEvent {
+    CombatStarted,
+    CombatEnded { victory: bool },
     TurnStarted { entity: EntityId },
     TurnEnded { entity: EntityId },
-    CardPlayed { card: u32, source: EntityId },
+    CardPlayed { card: u32, card_type: CardType, source: EntityId },
+    CardDrawn { card: u32 },
+    CardDiscarded { card: u32 },
+    CardExhausted { card: u32 },
+    /// A card entered the combat from outside the deck, e.g. an upgrade or a generated card.
+    CardCreated { card: u32, pile: CardPile },
+    CardRetained { card: u32 },
+    DrawPileShuffled,
     EnemyAction { enemy: EntityId },
-    DamageDealt { source: EntityId, target: EntityId, amount: i32 },
+    /// Fired for every hit of any kind; `amount` is what got past block.
+    DamageDealt { source: EntityId, target: EntityId, amount: i32, kind: DamageKind },
+    /// Any loss of HP, whether from damage, poison or a cost.
+    HpLost { entity: EntityId, amount: i32 },
+    Healed { entity: EntityId, amount: i32 },
+    /// `amount` is negative when max HP was lowered.
+    MaxHpChanged { entity: EntityId, amount: i32 },
+    TempHpGained { entity: EntityId, amount: i32 },
+    TempHpLost { entity: EntityId, amount: i32 },
     BlockGained { entity: EntityId, amount: i32 },
+    StatusApplied { entity: EntityId, status: StatusType, stacks: i32 },
+    StatusRemoved { entity: EntityId, status: StatusType },
+    EnergyGained { amount: i32 },
+    EnergySpent { amount: i32 },
+    EnemyDied { enemy: EntityId },
+    /// An enemy joined mid-combat, summoned or split off another.
+    EnemySpawned { enemy: EntityId },
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CardPile {
+    Draw,
+    Hand,
+    Discard,
+    Exhaust,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TurnRecord {
     pub turn_number: usize,
     pub cards_played: Vec<u32>,
-    pub enemy_intents: Vec<(usize, String)>,
+    pub enemy_intents: Vec<(EnemyId, String)>,
 }
 
 impl TurnRecord {
@@ -40,38 +96,157 @@ impl TurnRecord {
     }
 }
 
+/// An effect in play. `order` is handed out on registration and breaks
+/// priority ties, so dispatch order never depends on where an effect sits.
+#[derive(Clone)]
+struct EffectEntry {
+    order: u64,
+    owner: EntityId,
+    effect: Box<dyn Effect>,
+}
+
+#[derive(Clone)]
 pub struct GameState {
     player: Player,
-    enemies: Vec<Box<dyn Enemy>>,
-    effects: Vec<(EntityId, Box<dyn Effect>)>,
+    enemies: Vec<EnemySlot>,
+    graveyard: Vec<EnemySlot>,
+    /// Field position of every enemy in `enemies`, 0 being the leftmost.
+    positions: BTreeMap<EnemyId, usize>,
+    next_enemy_id: usize,
+    effects: Vec<EffectEntry>,
+    next_effect_order: u64,
+    status_registry: Rc<StatusRegistry>,
+    damage_modifiers: Vec<Rc<dyn DamageModifier>>,
+    action_queue: ActionQueue,
+    resolving_actions: bool,
+    deferred_resolution: bool,
+    event_queue: VecDeque<GameEvent>,
+    dispatching_events: bool,
     
-    draw_pile: Vec<Card>,
-    hand: Vec<Card>,
-    discard_pile: Vec<Card>,
-    exhaust_pile: Vec<Card>,
+    draw_pile: Pile,
+    hand: Pile,
+    discard_pile: Pile,
+    exhaust_pile: Pile,
     
-    turn_history: Vec<TurnRecord>,
+    turn_history: Rc<Vec<TurnRecord>>,
     current_turn_record: TurnRecord,
     turn_count: usize,
     
     pending_upgraded_card: Option<Card>,
+    
+    rng: GameRng,
+    combat_ended: bool,
+    
+    /// Shared with clones until either side records something, so searches
+    /// that clone the state every playout don't copy the whole log.
+    recording: Option<Rc<CombatRecording>>,
+}
+
+/// Last step of every played card: fires `CardPlayed` and moves the card to
+/// its destination pile once all of its actions have resolved.
+#[derive(Clone, Debug)]
+struct FinishCardAction {
+    card: Card,
+}
+
+impl Action for FinishCardAction {
+    fn resolve(&self, game_state: &mut GameState, source: EntityId, _targets: &[EntityId], _energy_spent: Option<i32>) {
+        let card_id = self.card.instance_id();
+        game_state.fire_event(GameEvent::CardPlayed { card: card_id, card_type: *self.card.card_type(), source });
+        
+        if self.card.exhaust() {
+            game_state.exhaust_pile.push(self.card.clone());
+            game_state.fire_event(GameEvent::CardExhausted { card: card_id });
+        } else {
+            game_state.discard_pile.push(self.card.clone());
+        }
+    }
+    
+    fn description(&self) -> String {
+        format!("Finish playing {}", self.card.name())
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
 }
 
 impl GameState {
     pub fn new(player: Player, enemies: Vec<Box<dyn Enemy>>) -> Self {
-        GameState {
+        Self::new_with_rng(player, enemies, Vec::new(), GameRng::from_entropy())
+    }
+    
+    /// Builds a combat whose shuffles and other random choices all come from
+    /// `rng`, so the same rng state and inputs always replay the same way.
+    /// Panics with more than `MAX_ENEMIES` enemies.
+    pub fn new_with_rng(player: Player, enemies: Vec<Box<dyn Enemy>>, starting_deck: Vec<Card>, rng: GameRng) -> Self {
+        assert!(enemies.len() <= MAX_ENEMIES, "A combat starts with at most {} enemies, got {}", MAX_ENEMIES, enemies.len());
+        let mut game = GameState {
             player,
-            enemies,
+            enemies: Vec::new(),
+            graveyard: Vec::new(),
+            positions: BTreeMap::new(),
+            next_enemy_id: 0,
             effects: Vec::new(),
-            draw_pile: Vec::new(),
-            hand: Vec::new(),
-            discard_pile: Vec::new(),
-            exhaust_pile: Vec::new(),
-            turn_history: Vec::new(),
+            next_effect_order: 0,
+            status_registry: Rc::new(StatusRegistry::builtin()),
+            damage_modifiers: Vec::new(),
+            action_queue: ActionQueue::new(),
+            resolving_actions: false,
+            deferred_resolution: false,
+            event_queue: VecDeque::new(),
+            dispatching_events: false,
+            draw_pile: Pile::new(),
+            hand: Pile::new(),
+            discard_pile: Pile::new(),
+            exhaust_pile: Pile::new(),
+            turn_history: Rc::new(Vec::new()),
             current_turn_record: TurnRecord::new(0),
             turn_count: 0,
             pending_upgraded_card: None,
+            rng,
+            combat_ended: false,
+            recording: None,
+        };
+        
+        for (slot, enemy) in enemies.into_iter().enumerate() {
+            let id = game.allocate_enemy_id();
+            game.enemies.push((id, enemy));
+            game.positions.insert(id, slot);
         }
+        
+        game.draw_pile = Pile::from(starting_deck);
+        game.shuffle_draw_pile();
+        
+        game
+    }
+    
+    /// Puts the starting enemies at the given field positions, in roster
+    /// order, instead of side by side from the left. Needs one free slot per
+    /// enemy.
+    pub fn with_enemy_slots(mut self, slots: &[usize]) -> Result<Self, String> {
+        if slots.len() != self.enemies.len() {
+            return Err(format!("Got {} slots for {} enemies", slots.len(), self.enemies.len()));
+        }
+        if let Some(slot) = slots.iter().find(|slot| **slot >= MAX_ENEMIES) {
+            return Err(format!("Slot {} is off the field", slot));
+        }
+        if (1..slots.len()).any(|i| slots[..i].contains(&slots[i])) {
+            return Err("Two enemies cannot share a slot".to_string());
+        }
+        
+        for ((id, _), slot) in self.enemies.iter().zip(slots) {
+            self.positions.insert(*id, *slot);
+        }
+        let positions = &self.positions;
+        self.enemies.sort_by_key(|(id, _)| positions.get(id).copied());
+        Ok(self)
+    }
+    
+    fn allocate_enemy_id(&mut self) -> EnemyId {
+        let id = EnemyId(self.next_enemy_id);
+        self.next_enemy_id += 1;
+        id
     }
     
     pub fn player(&self) -> &Player {
@@ -82,29 +257,75 @@ impl GameState {
         &mut self.player
     }
     
-    pub fn enemies(&self) -> &[Box<dyn Enemy>] {
+    /// Enemies still on the field, left to right. Enemies that died keep
+    /// their place here until `remove_dead_enemies` moves them to the graveyard.
+    pub fn enemies(&self) -> &[EnemySlot] {
         &self.enemies
     }
     
-    pub fn enemies_mut(&mut self) -> &mut Vec<Box<dyn Enemy>> {
-        &mut self.enemies
+    pub fn enemy(&self, id: EnemyId) -> Option<&dyn Enemy> {
+        self.enemies
+            .iter()
+            .find(|(enemy_id, _)| *enemy_id == id)
+            .map(|(_, enemy)| enemy.as_ref())
+    }
+    
+    pub fn enemy_mut(&mut self, id: EnemyId) -> Option<&mut dyn Enemy> {
+        self.enemies
+            .iter_mut()
+            .find(|(enemy_id, _)| *enemy_id == id)
+            .map(|(_, enemy)| -> &mut dyn Enemy { enemy.as_mut() })
+    }
+    
+    /// Enemies removed from the field, in the order they died.
+    pub fn graveyard(&self) -> &[EnemySlot] {
+        &self.graveyard
+    }
+    
+    pub fn dead_enemy(&self, id: EnemyId) -> Option<&dyn Enemy> {
+        self.graveyard
+            .iter()
+            .find(|(enemy_id, _)| *enemy_id == id)
+            .map(|(_, enemy)| enemy.as_ref())
     }
     
     pub fn get_all_living_enemies(&self) -> Vec<EntityId> {
         self.enemies
             .iter()
-            .enumerate()
             .filter(|(_, enemy)| enemy.is_alive())
-            .map(|(id, _)| EntityId::Enemy(id))
+            .map(|(id, _)| EntityId::Enemy(*id))
             .collect()
     }
     
     pub fn get_all_enemy_ids(&self) -> Vec<EntityId> {
-        (0..self.enemies.len())
-            .map(|id| EntityId::Enemy(id))
+        self.enemy_ids()
+            .into_iter()
+            .map(EntityId::Enemy)
             .collect()
     }
     
+    pub fn enemy_ids(&self) -> Vec<EnemyId> {
+        self.enemies.iter().map(|(id, _)| *id).collect()
+    }
+    
+    /// Where an enemy stands, 0 being the leftmost of `MAX_ENEMIES` positions.
+    /// It keeps its position while others die or join.
+    pub fn enemy_slot(&self, id: EnemyId) -> Option<usize> {
+        self.positions.get(&id).copied()
+    }
+    
+    /// Positions not held by a living enemy, nearest to `preferred` first.
+    fn free_slots(&self, preferred: usize) -> Vec<usize> {
+        let taken: Vec<usize> = self.enemies
+            .iter()
+            .filter(|(_, enemy)| enemy.is_alive())
+            .filter_map(|(id, _)| self.enemy_slot(*id))
+            .collect();
+        let mut free: Vec<usize> = (0..MAX_ENEMIES).filter(|slot| !taken.contains(slot)).collect();
+        free.sort_by_key(|slot| (slot.abs_diff(preferred), *slot));
+        free
+    }
+    
     pub fn draw_pile(&self) -> &[Card] {
         &self.draw_pile
     }
@@ -137,52 +358,376 @@ impl GameState {
         self.current_turn_record.cards_played.is_empty()
     }
     
-    pub fn record_enemy_intent(&mut self, enemy_id: usize, intent_description: String) {
+    pub fn record_enemy_intent(&mut self, enemy_id: EnemyId, intent_description: String) {
         self.current_turn_record.enemy_intents.push((enemy_id, intent_description));
     }
     
     pub fn new_with_deck(player: Player, enemies: Vec<Box<dyn Enemy>>, starting_deck: Vec<Card>) -> Self {
-        let mut game = Self::new(player, enemies);
-        game.draw_pile = starting_deck;
-        game.shuffle_draw_pile();
-        game
+        Self::new_with_rng(player, enemies, starting_deck, GameRng::from_entropy())
+    }
+    
+    /// Copies everything needed to resume this combat later, including the
+    /// rng position, into plain serializable data.
+    pub fn snapshot(&self) -> GameStateSnapshot {
+        let snapshot_enemy = |(enemy_id, enemy): &EnemySlot| EnemySnapshot {
+            enemy_id: *enemy_id,
+            id: enemy.id().to_string(),
+            state: enemy.save_state(),
+            slot: self.enemy_slot(*enemy_id),
+        };
+        
+        GameStateSnapshot {
+            player: self.player.clone(),
+            enemies: self.enemies.iter().map(snapshot_enemy).collect(),
+            graveyard: self.graveyard.iter().map(snapshot_enemy).collect(),
+            next_enemy_id: self.next_enemy_id,
+            effects: self.effects
+                .iter()
+                .map(|entry| EffectSnapshot {
+                    owner: entry.owner,
+                    id: entry.effect.id().to_string(),
+                    state: entry.effect.save_state(),
+                })
+                .collect(),
+            damage_modifiers: self.damage_modifiers
+                .iter()
+                .map(|modifier| DamageModifierSnapshot {
+                    id: modifier.id().to_string(),
+                    state: modifier.save_state(),
+                })
+                .collect(),
+            statuses: (*self.status_registry != StatusRegistry::builtin()).then(|| {
+                let mut definitions: Vec<StatusDefinition> = self.status_registry.definitions().cloned().collect();
+                definitions.sort_by(|a, b| a.id.id().cmp(b.id.id()));
+                definitions
+            }),
+            draw_pile: snapshot_cards(&self.draw_pile),
+            hand: snapshot_cards(&self.hand),
+            discard_pile: snapshot_cards(&self.discard_pile),
+            exhaust_pile: snapshot_cards(&self.exhaust_pile),
+            turn_history: self.turn_history.to_vec(),
+            current_turn_record: self.current_turn_record.clone(),
+            turn_count: self.turn_count,
+            pending_upgraded_card: self.pending_upgraded_card.as_ref().map(CardSnapshot::from_card),
+            rng: self.rng.clone(),
+            combat_ended: self.combat_ended,
+        }
+    }
+    
+    /// Rebuilds a combat from a snapshot, recreating cards, effects and
+    /// enemies through `registry`.
+    pub fn from_snapshot(snapshot: GameStateSnapshot, registry: &Registry) -> Result<Self, String> {
+        let restore_enemies = |enemies: &[EnemySnapshot]| -> Result<Vec<EnemySlot>, String> {
+            enemies
+                .iter()
+                .map(|enemy| Ok((enemy.enemy_id, registry.load_enemy(&enemy.id, &enemy.state)?)))
+                .collect()
+        };
+        
+        let effects = snapshot.effects
+            .iter()
+            .enumerate()
+            .map(|(order, effect)| Ok(EffectEntry {
+                order: order as u64,
+                owner: effect.owner,
+                effect: registry.load_effect(&effect.id, &effect.state)?,
+            }))
+            .collect::<Result<Vec<_>, String>>()?;
+        
+        let damage_modifiers = snapshot.damage_modifiers
+            .iter()
+            .map(|modifier| registry.load_damage_modifier(&modifier.id, &modifier.state))
+            .collect::<Result<Vec<_>, String>>()?;
+        let status_registry = match snapshot.statuses {
+            Some(definitions) => {
+                let mut statuses = StatusRegistry::new();
+                for definition in definitions {
+                    statuses.register(definition);
+                }
+                statuses
+            }
+            None => StatusRegistry::builtin(),
+        };
+        
+        let pending_upgraded_card = match &snapshot.pending_upgraded_card {
+            Some(card) => Some(card.restore(registry)?),
+            None => None,
+        };
+        
+        let positions = snapshot.enemies
+            .iter()
+            .enumerate()
+            .map(|(index, enemy)| (enemy.enemy_id, enemy.slot.unwrap_or(index)))
+            .collect();
+        
+        Ok(GameState {
+            player: snapshot.player,
+            enemies: restore_enemies(&snapshot.enemies)?,
+            graveyard: restore_enemies(&snapshot.graveyard)?,
+            positions,
+            next_enemy_id: snapshot.next_enemy_id,
+            next_effect_order: effects.len() as u64,
+            status_registry: Rc::new(status_registry),
+            damage_modifiers,
+            action_queue: ActionQueue::new(),
+            resolving_actions: false,
+            deferred_resolution: false,
+            effects,
+            event_queue: VecDeque::new(),
+            dispatching_events: false,
+            draw_pile: Pile::from(restore_cards(&snapshot.draw_pile, registry)?),
+            hand: Pile::from(restore_cards(&snapshot.hand, registry)?),
+            discard_pile: Pile::from(restore_cards(&snapshot.discard_pile, registry)?),
+            exhaust_pile: Pile::from(restore_cards(&snapshot.exhaust_pile, registry)?),
+            turn_history: Rc::new(snapshot.turn_history),
+            current_turn_record: snapshot.current_turn_record,
+            turn_count: snapshot.turn_count,
+            pending_upgraded_card,
+            rng: snapshot.rng,
+            combat_ended: snapshot.combat_ended,
+            recording: None,
+        })
+    }
+    
+    pub fn rng(&self) -> &GameRng {
+        &self.rng
+    }
+    
+    pub fn rng_mut(&mut self) -> &mut GameRng {
+        &mut self.rng
+    }
+    
+    /// Replaces what the player cannot know, the draw order and every future
+    /// random roll, with a fresh sample. Used by search on cloned states.
+    pub fn determinize(&mut self, seed: u64) {
+        self.rng = GameRng::new(seed);
+        let rng = self.rng.stream(RngStream::Shuffle);
+        self.draw_pile.to_mut().shuffle(rng);
+    }
+    
+    pub fn seed(&self) -> u64 {
+        self.rng.seed()
     }
     
     pub fn add_effect(&mut self, owner: EntityId, effect: Box<dyn Effect>) {
-        self.effects.push((owner, effect));
+        let order = self.next_effect_order;
+        self.next_effect_order += 1;
+        self.effects.push(EffectEntry { order, owner, effect });
+    }
+    
+    /// Starts logging every input and event from the current state onwards.
+    pub fn start_recording(&mut self) {
+        self.recording = Some(Rc::new(CombatRecording {
+            initial: self.snapshot(),
+            log: CombatLog::new(self.entity_states()),
+        }));
+    }
+    
+    pub fn stop_recording(&mut self) -> Option<CombatRecording> {
+        self.recording.take().map(Rc::unwrap_or_clone)
+    }
+    
+    pub fn recording(&self) -> Option<&CombatRecording> {
+        self.recording.as_deref()
+    }
+    
+    fn entity_states(&self) -> EntityStates {
+        let mut states = vec![(EntityId::Player, EntityState::of(&self.player))];
+        for (id, enemy) in self.enemies.iter().chain(self.graveyard.iter()) {
+            states.push((EntityId::Enemy(*id), EntityState::of(enemy.as_ref())));
+        }
+        states
     }
     
     pub fn fire_event(&mut self, event: GameEvent) {
-        let mut effects = std::mem::take(&mut self.effects);
+        if self.recording.is_some() {
+            let states = self.entity_states();
+            let turn = self.turn_count;
+            if let Some(recording) = &mut self.recording {
+                Rc::make_mut(recording).log.record_event(turn, &event, states);
+            }
+        }
         
-        for (owner, effect) in effects.iter_mut() {
-            effect.on_event(&event, *owner, self);
+        self.event_queue.push_back(event);
+        if self.dispatching_events {
+            return;
         }
         
-        effects.retain(|(_, e)| !e.should_remove());
-        self.effects = effects;
+        self.dispatching_events = true;
+        while let Some(event) = self.event_queue.pop_front() {
+            self.dispatch_event(&event);
+        }
+        self.dispatching_events = false;
     }
     
-    fn get_status(&self, entity: EntityId, status_type: StatusType) -> i32 {
+    /// Hands one event to every effect that was in play when dispatch began,
+    /// in priority order. Each effect is taken out while it runs so it can
+    /// freely change the game state; effects it adds join after this event.
+    fn dispatch_event(&mut self, event: &GameEvent) {
+        let mut order: Vec<(i32, u64)> = self.effects
+            .iter()
+            .map(|entry| (entry.effect.priority(), entry.order))
+            .collect();
+        order.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        
+        for (_, effect_order) in order {
+            let Some(index) = self.effects.iter().position(|entry| entry.order == effect_order) else {
+                continue;
+            };
+            
+            let mut entry = self.effects.remove(index);
+            entry.effect.on_event(event, entry.owner, self);
+            
+            if !entry.effect.should_remove() {
+                let index = index.min(self.effects.len());
+                self.effects.insert(index, entry);
+            }
+        }
+        
+        // A dead enemy's powers see its death, then go with it.
+        if let GameEvent::EnemyDied { enemy } = event {
+            self.effects.retain(|entry| entry.owner != *enemy);
+        }
+    }
+    
+    pub fn effects(&self) -> impl Iterator<Item = (EntityId, &dyn Effect)> {
+        self.effects.iter().map(|entry| (entry.owner, entry.effect.as_ref()))
+    }
+    
+    pub fn get_status(&self, entity: EntityId, status_type: StatusType) -> i32 {
         match entity {
             EntityId::Player => self.player.get_status(&status_type),
             EntityId::Enemy(id) => {
-                self.enemies.get(id)
+                self.enemy(id)
                     .map(|e| e.get_status(&status_type))
                     .unwrap_or(0)
             }
         }
     }
     
+    pub fn status_registry(&self) -> &StatusRegistry {
+        &self.status_registry
+    }
+    
+    /// Swaps in a different set of status definitions, e.g. to add custom statuses.
+    pub fn set_status_registry(&mut self, registry: StatusRegistry) {
+        self.status_registry = Rc::new(registry);
+    }
+    
+    pub fn status_definition(&self, status_type: &StatusType) -> Option<&StatusDefinition> {
+        self.status_registry.get(status_type)
+    }
+    
+    /// Applies stacks of a status. A debuff, or negative stacks of a status that
+    /// allows them (losing Strength), is negated by one stack of Artifact instead.
+    /// Statuses that cannot go negative stop at zero.
     pub fn add_status(&mut self, entity: EntityId, status_type: StatusType, stacks: i32) {
+        if !self.is_entity_alive(entity) {
+            return;
+        }
+        
+        let (is_debuff, allows_negative) = self.status_definition(&status_type)
+            .map(|definition| (definition.is_debuff(), definition.allows_negative))
+            .unwrap_or((false, false));
+        
+        let is_harmful = (is_debuff && stacks > 0) || (allows_negative && stacks < 0);
+        if is_harmful && self.get_status(entity, StatusType::ARTIFACT) > 0 {
+            self.reduce_status(entity, StatusType::ARTIFACT, 1);
+            return;
+        }
+        
+        let stacks = if allows_negative {
+            stacks
+        } else {
+            stacks.max(-self.get_status(entity, status_type.clone()))
+        };
+        if stacks == 0 {
+            return;
+        }
+        
         match entity {
-            EntityId::Player => {
-                self.player.add_status(status_type, stacks);
+            EntityId::Player => self.player.add_status(status_type.clone(), stacks),
+            EntityId::Enemy(id) => {
+                if let Some(enemy) = self.enemy_mut(id) {
+                    enemy.add_status(status_type.clone(), stacks);
+                }
             }
+        }
+        
+        self.fire_event(GameEvent::StatusApplied { entity, status: status_type.clone(), stacks });
+        
+        if self.get_status(entity, status_type.clone()) == 0 {
+            self.fire_event(GameEvent::StatusRemoved { entity, status: status_type });
+        }
+    }
+    
+    /// Takes stacks off a status, firing `StatusRemoved` if it runs out.
+    fn reduce_status(&mut self, entity: EntityId, status_type: StatusType, amount: i32) {
+        let before = self.status_types(entity);
+        match entity {
+            EntityId::Player => self.player.reduce_status(status_type, amount),
             EntityId::Enemy(id) => {
-                if let Some(enemy) = self.enemies.get_mut(id) {
-                    enemy.add_status(status_type, stacks);
+                if let Some(enemy) = self.enemy_mut(id) {
+                    enemy.reduce_status(status_type, amount);
+                }
+            }
+        }
+        self.fire_removed_statuses(entity, before);
+    }
+    
+    /// Decays every status of the entity whose definition decays at `timing`.
+    fn decay_statuses(&mut self, entity: EntityId, timing: DecayTiming) {
+        let decaying: Vec<(StatusType, i32, Option<StatusType>)> = self.status_types(entity)
+            .into_iter()
+            .filter_map(|status| {
+                let definition = self.status_definition(&status)?;
+                if definition.decay != timing {
+                    return None;
                 }
+                let stacks = self.get_status(entity, status.clone());
+                let amount = match definition.stacking {
+                    Stacking::Intensity => stacks,
+                    Stacking::Duration | Stacking::Counter => stacks.signum(),
+                };
+                Some((status, amount, definition.decays_into.clone()))
+            })
+            .collect();
+        
+        for (status, amount, decays_into) in decaying {
+            self.reduce_status(entity, status, amount);
+            
+            if let Some(decays_into) = decays_into {
+                self.add_status(entity, decays_into, amount);
+            }
+        }
+    }
+    
+    /// Metallicize and Plated Armor both turn into block as their owner's turn ends.
+    fn gain_end_of_turn_block(&mut self, entity: EntityId) {
+        let block = self.get_status(entity, StatusType::METALLICIZE) + self.get_status(entity, StatusType::PLATED_ARMOR);
+        if block > 0 {
+            self.modify_block(entity, block);
+            self.fire_event(GameEvent::BlockGained { entity, amount: block });
+        }
+    }
+    
+    fn status_types(&self, entity: EntityId) -> Vec<StatusType> {
+        let statuses = match entity {
+            EntityId::Player => self.player.get_all_statuses(),
+            EntityId::Enemy(id) => match self.enemy(id) {
+                Some(enemy) => enemy.get_all_statuses(),
+                None => return Vec::new(),
+            },
+        };
+        statuses.iter().map(|s| s.status_type.clone()).collect()
+    }
+    
+    /// Fires `StatusRemoved` for every status in `before` the entity no longer has.
+    fn fire_removed_statuses(&mut self, entity: EntityId, before: Vec<StatusType>) {
+        let after = self.status_types(entity);
+        for status in before {
+            if !after.contains(&status) {
+                self.fire_event(GameEvent::StatusRemoved { entity, status });
             }
         }
     }
@@ -191,7 +736,7 @@ impl GameState {
         match entity {
             EntityId::Player => self.player.get_block(),
             EntityId::Enemy(id) => {
-                self.enemies.get(id)
+                self.enemy(id)
                     .map(|e| e.get_block())
                     .unwrap_or(0)
             }
@@ -205,7 +750,7 @@ impl GameState {
                 self.player.set_block(new_block);
             }
             EntityId::Enemy(id) => {
-                if let Some(enemy) = self.enemies.get_mut(id) {
+                if let Some(enemy) = self.enemy_mut(id) {
                     let new_block = (enemy.get_block() + delta).max(0);
                     enemy.set_block(new_block);
                 }
@@ -214,71 +759,247 @@ impl GameState {
     }
     
     fn modify_hp(&mut self, entity: EntityId, delta: i32) {
-        match entity {
+        let (old_hp, new_hp) = match entity {
             EntityId::Player => {
-                let new_hp = (self.player.get_current_health() + delta)
-                    .clamp(0, self.player.get_max_health());
+                let old_hp = self.player.get_current_health();
+                let new_hp = (old_hp + delta).clamp(0, self.player.get_max_health());
                 self.player.set_health(new_hp);
+                (old_hp, new_hp)
             }
             EntityId::Enemy(id) => {
-                if let Some(enemy) = self.enemies.get_mut(id) {
-                    let new_hp = (enemy.get_current_health() + delta)
-                        .clamp(0, enemy.get_max_health());
-                    enemy.set_health(new_hp);
+                match self.enemy_mut(id) {
+                    Some(enemy) => {
+                        let old_hp = enemy.get_current_health();
+                        let new_hp = (old_hp + delta).clamp(0, enemy.get_max_health());
+                        enemy.set_health(new_hp);
+                        (old_hp, new_hp)
+                    }
+                    None => return,
                 }
             }
+        };
+        
+        if new_hp < old_hp {
+            self.fire_event(GameEvent::HpLost { entity, amount: old_hp - new_hp });
+        } else if new_hp > old_hp {
+            self.fire_event(GameEvent::Healed { entity, amount: new_hp - old_hp });
+        }
+        
+        if old_hp > 0 && new_hp == 0 && matches!(entity, EntityId::Enemy(_)) {
+            self.fire_event(GameEvent::EnemyDied { enemy: entity });
         }
     }
-
+    
+    pub fn gain_energy(&mut self, amount: i32) {
+        self.player.gain_energy(amount);
+        self.fire_event(GameEvent::EnergyGained { amount });
+    }
+    
+    fn spend_energy(&mut self, amount: i32) {
+        self.player.spend_energy(amount);
+        if amount > 0 {
+            self.fire_event(GameEvent::EnergySpent { amount });
+        }
+    }
+    
+    /// An attack from `source`; see `apply_damage` for other kinds of damage.
     pub fn deal_damage(
         &mut self,
         source: EntityId,
         target: EntityId,
         base_damage: i32,
     ) {
-        let mut dmg = base_damage;
+        self.apply_damage(DamageKind::Attack, source, target, base_damage);
+    }
+    
+    /// Runs a hit through the pipeline and applies it. The kind decides which
+    /// stages and whether block apply; attacks also set off Plated Armor and Thorns.
+    pub fn apply_damage(&mut self, kind: DamageKind, source: EntityId, target: EntityId, base_damage: i32) -> DamageResult {
+        if !self.is_entity_alive(target) {
+            return DamageResult { damage: 0, blocked: 0, temp_hp_lost: 0, hp_loss: 0 };
+        }
         
-        let strength = self.get_status(source, StatusType::Strength);
-        dmg += strength;
+        let result = self.calculate_damage(kind, source, Some(target), base_damage);
         
-        let weak = self.get_status(source, StatusType::Weak);
-        if weak > 0 {
-            dmg = (dmg as f32 * 0.75).floor() as i32;
+        self.modify_block(target, -result.blocked);
+        if result.temp_hp_lost > 0 {
+            self.modify_temp_hp(target, -result.temp_hp_lost);
         }
+        self.modify_hp(target, -result.hp_loss);
         
-        let vulnerable = self.get_status(target, StatusType::Vulnerable);
-        if vulnerable > 0 {
-            dmg = (dmg as f32 * 1.5).floor() as i32;
+        self.fire_event(GameEvent::DamageDealt {
+            source,
+            target,
+            amount: result.hp_loss,
+            kind,
+        });
+        
+        if kind == DamageKind::Attack {
+            if result.hp_loss > 0 && self.get_status(target, StatusType::PLATED_ARMOR) > 0 {
+                self.reduce_status(target, StatusType::PLATED_ARMOR, 1);
+            }
+            
+            let thorns = self.get_status(target, StatusType::THORNS);
+            if thorns > 0 && source != target {
+                self.apply_damage(DamageKind::Thorns, target, source, thorns);
+            }
         }
         
-        dmg = dmg.max(0);
+        result
+    }
+    
+    /// Registers a modifier that is not tied to any effect or status, e.g. a relic.
+    pub fn add_damage_modifier(&mut self, modifier: Rc<dyn DamageModifier>) {
+        self.damage_modifiers.push(modifier);
+    }
+    
+    /// Runs one hit through every stage of the damage pipeline without
+    /// changing anything. `deal_damage` and the UI's previews both use this.
+    pub fn calculate_damage(&self, kind: DamageKind, source: EntityId, target: Option<EntityId>, base_damage: i32) -> DamageResult {
+        let context = DamageContext { kind, source, target, base: base_damage };
+        let mut damage = base_damage as f32;
         
-        let block = self.get_block(target);
-        let absorbed = dmg.min(block);
-        let final_damage = dmg - absorbed;
+        for stage in DamageStage::ALL {
+            if kind.uses_stage(stage) {
+                damage = self.apply_damage_stage(stage, &context, damage);
+            }
+            
+            // Fractions are dropped once, after every multiplier has been applied.
+            if stage == DamageStage::DefenderMultiplicative {
+                damage = damage.floor().max(0.0);
+            }
+        }
         
-        self.modify_block(target, -absorbed);
-        self.modify_hp(target, -final_damage);
+        let damage = damage.max(0.0) as i32;
+        let (blocked, temp_hp_lost) = match target {
+            Some(target) if kind.is_blockable() => {
+                let blocked = damage.min(self.get_block(target));
+                (blocked, (damage - blocked).min(self.get_temp_hp(target)))
+            }
+            _ => (0, 0),
+        };
         
-        self.fire_event(GameEvent::DamageDealt {
-            source,
-            target,
-            amount: final_damage,
-        });
+        DamageResult { damage, blocked, temp_hp_lost, hp_loss: damage - blocked - temp_hp_lost }
     }
     
-    pub fn gain_block(&mut self, entity: EntityId, base_block: i32) {
-        let mut block = base_block;
+    fn apply_damage_stage(&self, stage: DamageStage, context: &DamageContext, mut damage: f32) -> f32 {
+        let holders = [(Some(context.source), DamageHolder::Attacker), (context.target, DamageHolder::Defender)];
+        for (entity, holder) in holders {
+            let Some(entity) = entity else { continue };
+            for status in self.status_types(entity) {
+                let Some(modifier) = self.status_definition(&status).and_then(|definition| definition.damage_modifier) else {
+                    continue;
+                };
+                if modifier.stage == stage && modifier.holder == holder {
+                    damage = modifier.apply(self.get_status(entity, status), damage);
+                }
+            }
+        }
+        
+        for entry in &self.effects {
+            damage = entry.effect.modify_damage(entry.owner, stage, context, damage);
+        }
+        
+        for modifier in &self.damage_modifiers {
+            if modifier.stage() == stage {
+                damage = modifier.modify(context, self, damage);
+            }
+        }
         
-        let dexterity = self.get_status(entity, StatusType::Dexterity);
-        block += dexterity;
+        damage
+    }
+    
+    /// Heals after the player's healing percent and every effect's
+    /// `modify_heal`. Returns the HP actually restored.
+    pub fn heal(&mut self, entity: EntityId, amount: i32) -> i32 {
+        if !self.is_entity_alive(entity) {
+            return 0;
+        }
+        
+        let mut amount = match entity {
+            EntityId::Player => self.player.healing_amount(amount),
+            EntityId::Enemy(_) => amount,
+        };
+        for entry in &self.effects {
+            amount = entry.effect.modify_heal(entry.owner, entity, amount);
+        }
         
-        let frail = self.get_status(entity, StatusType::Frail);
-        if frail > 0 {
-            block = (block as f32 * 0.75).floor() as i32;
+        let before = self.get_current_hp(entity);
+        self.modify_hp(entity, amount.max(0));
+        self.get_current_hp(entity) - before
+    }
+    
+    /// Raises max HP and heals by the same amount; healing modifiers don't apply.
+    pub fn raise_max_hp(&mut self, entity: EntityId, amount: i32) {
+        if amount <= 0 || !self.is_entity_alive(entity) {
+            return;
+        }
+        
+        let Some(state) = self.state_mut(entity) else { return };
+        state.set_max_health(state.get_max_health() + amount);
+        self.fire_event(GameEvent::MaxHpChanged { entity, amount });
+        self.modify_hp(entity, amount);
+    }
+    
+    /// Lowers max HP, never below 1. HP above the new maximum is lost.
+    pub fn lower_max_hp(&mut self, entity: EntityId, amount: i32) {
+        if amount <= 0 || !self.is_entity_alive(entity) {
+            return;
+        }
+        
+        let Some(state) = self.state_mut(entity) else { return };
+        let (old_max, old_hp) = (state.get_max_health(), state.get_current_health());
+        state.set_max_health(old_max - amount);
+        let (new_max, new_hp) = (state.get_max_health(), state.get_current_health());
+        
+        self.fire_event(GameEvent::MaxHpChanged { entity, amount: new_max - old_max });
+        if new_hp < old_hp {
+            self.fire_event(GameEvent::HpLost { entity, amount: old_hp - new_hp });
+        }
+    }
+    
+    pub fn gain_temp_hp(&mut self, entity: EntityId, amount: i32) {
+        if amount > 0 && self.is_entity_alive(entity) {
+            self.modify_temp_hp(entity, amount);
         }
+    }
+    
+    pub fn get_temp_hp(&self, entity: EntityId) -> i32 {
+        match entity {
+            EntityId::Player => self.player.get_temp_hp(),
+            EntityId::Enemy(id) => self.enemy(id).map(|e| e.get_temp_hp()).unwrap_or(0),
+        }
+    }
+    
+    fn modify_temp_hp(&mut self, entity: EntityId, delta: i32) {
+        let Some(state) = self.state_mut(entity) else { return };
+        let old = state.get_temp_hp();
+        state.set_temp_hp(old + delta);
+        let new = state.get_temp_hp();
         
-        block = block.max(0);
+        if new > old {
+            self.fire_event(GameEvent::TempHpGained { entity, amount: new - old });
+        } else if new < old {
+            self.fire_event(GameEvent::TempHpLost { entity, amount: old - new });
+        }
+    }
+    
+    fn get_current_hp(&self, entity: EntityId) -> i32 {
+        match entity {
+            EntityId::Player => self.player.get_current_health(),
+            EntityId::Enemy(id) => self.enemy(id).map(|e| e.get_current_health()).unwrap_or(0),
+        }
+    }
+    
+    fn state_mut(&mut self, entity: EntityId) -> Option<&mut dyn State> {
+        match entity {
+            EntityId::Player => Some(&mut self.player),
+            EntityId::Enemy(id) => self.enemy_mut(id).map(|enemy| enemy as &mut dyn State),
+        }
+    }
+    
+    pub fn gain_block(&mut self, entity: EntityId, base_block: i32) {
+        let block = self.block_amount(entity, base_block);
         
         self.modify_block(entity, block);
         
@@ -288,19 +1009,50 @@ impl GameState {
         });
     }
     
-    pub fn play_card(&mut self, hand_index: usize, target: Option<EntityId>) -> Result<(), String> {
-        if hand_index >= self.hand.len() {
-            return Err("Card index out of bounds".to_string());
+    /// Block `entity` would get from `base_block` after the block modifiers of
+    /// its statuses, such as Dexterity and Frail, and every effect's `modify_block`.
+    fn block_amount(&self, entity: EntityId, base_block: i32) -> i32 {
+        let modifiers: Vec<(DamageOperation, i32)> = self.status_types(entity)
+            .into_iter()
+            .filter_map(|status| {
+                let operation = self.status_definition(&status)?.block_modifier?;
+                Some((operation, self.get_status(entity, status)))
+            })
+            .collect();
+        
+        let mut block = base_block as f32;
+        for additive in [true, false] {
+            for &(operation, stacks) in modifiers.iter().filter(|(operation, _)| operation.is_additive() == additive) {
+                block = operation.apply(stacks, block);
+            }
+        }
+        for entry in &self.effects {
+            block = entry.effect.modify_block(entry.owner, entity, block);
+        }
+        
+        (block.floor() as i32).max(0)
+    }
+    
+    /// Resolves who a card would hit, failing for the same reasons `play_card` would.
+    fn card_targets(&self, hand_index: usize, target: Option<EntityId>) -> Result<Vec<EntityId>, String> {
+        let card = self.hand.get(hand_index).ok_or("Card index out of bounds")?;
+        
+        if *card.card_type() == CardType::Attack && self.player.get_status(&StatusType::ENTANGLED) > 0 {
+            return Err("Entangled: Attacks cannot be played this turn".to_string());
         }
         
-        let card = &self.hand[hand_index];
-        let targeting = card.targeting();
+        match card.get_current_cost()? {
+            Some(cost) if self.player.get_energy() < cost => {
+                return Err(format!("Not enough energy: need {}, have {}", cost, self.player.get_energy()));
+            }
+            _ => {}
+        }
         
-        let actual_targets: Vec<EntityId> = match targeting {
+        let targets = match card.targeting() {
             CardTargeting::SingleEnemy => {
                 match target {
                     Some(EntityId::Enemy(id)) => {
-                        if id >= self.enemies.len() || self.enemies[id].get_current_health() == 0 {
+                        if !self.enemy(id).is_some_and(|enemy| enemy.is_alive()) {
                             return Err("Invalid enemy target".to_string());
                         }
                         vec![EntityId::Enemy(id)]
@@ -319,24 +1071,22 @@ impl GameState {
             }
         };
         
-        let energy_spent = if let EntityId::Player = EntityId::Player {
-            match card.get_current_cost() {
-                Ok(Some(cost)) => {
-                    if self.player.get_energy() < cost {
-                        return Err(format!("Not enough energy: need {}, have {}", cost, self.player.get_energy()));
-                    }
-                    self.player.spend_energy(cost);
-                    None
-                }
-                Ok(None) => {
-                    let energy = self.player.get_energy();
-                    self.player.spend_energy(energy);
-                    Some(energy)
-                }
-                Err(e) => return Err(e),
+        Ok(targets)
+    }
+    
+    pub fn play_card(&mut self, hand_index: usize, target: Option<EntityId>) -> Result<(), String> {
+        let actual_targets = self.card_targets(hand_index, target)?;
+        
+        let energy_spent = match self.hand[hand_index].get_current_cost()? {
+            Some(cost) => {
+                self.spend_energy(cost);
+                None
+            }
+            None => {
+                let energy = self.player.get_energy();
+                self.spend_energy(energy);
+                Some(energy)
             }
-        } else {
-            None
         };
         
         let card = self.remove_from_hand(hand_index).unwrap();
@@ -344,28 +1094,90 @@ impl GameState {
         self.current_turn_record.cards_played.push(card.instance_id());
         
         for effect in card.effects() {
-            effect.resolve(self, EntityId::Player, &actual_targets, energy_spent);
+            self.queue_action_bottom(effect.clone_box(), EntityId::Player, &actual_targets, energy_spent);
         }
-
-        self.fire_event(GameEvent::CardPlayed {
-            card: card.instance_id(),
-            source: EntityId::Player,
-        });
+        self.queue_action_bottom(Box::new(FinishCardAction { card }), EntityId::Player, &[], None);
         
-        if card.exhaust() {
-            self.exhaust_pile.push(card);
-        } else {
-            self.add_card_to_discard(card);
+        if !self.deferred_resolution {
+            self.resolve_actions();
+        }
+        
+        Ok(())
+    }
+    
+    pub fn queue_action_top(&mut self, action: Box<dyn Action>, source: EntityId, targets: &[EntityId], energy_spent: Option<i32>) {
+        self.action_queue.push_top(QueuedAction { action, source, targets: targets.to_vec(), energy_spent });
+    }
+    
+    pub fn queue_action_bottom(&mut self, action: Box<dyn Action>, source: EntityId, targets: &[EntityId], energy_spent: Option<i32>) {
+        self.action_queue.push_bottom(QueuedAction { action, source, targets: targets.to_vec(), energy_spent });
+    }
+    
+    pub fn pending_actions(&self) -> &ActionQueue {
+        &self.action_queue
+    }
+    
+    pub fn has_pending_actions(&self) -> bool {
+        !self.action_queue.is_empty()
+    }
+    
+    /// When on, card plays only queue their actions and the caller drives them
+    /// with `step_action`, e.g. to animate each step. Enemy turns always resolve in full.
+    pub fn set_deferred_resolution(&mut self, deferred: bool) {
+        self.deferred_resolution = deferred;
+    }
+    
+    fn is_entity_alive(&self, entity: EntityId) -> bool {
+        match entity {
+            EntityId::Player => self.player.is_alive(),
+            EntityId::Enemy(id) => self.enemy(id).is_some_and(|enemy| enemy.is_alive()),
+        }
+    }
+    
+    /// Resolves the next queued action and returns it. Actions whose source
+    /// has died, or whose targets have all died, are skipped.
+    pub fn step_action(&mut self) -> Option<QueuedAction> {
+        let mut queued = self.action_queue.pop()?;
+        
+        let had_targets = !queued.targets.is_empty();
+        queued.targets.retain(|&target| self.is_entity_alive(target));
+        let source_alive = matches!(queued.source, EntityId::Player) || self.is_entity_alive(queued.source);
+        
+        if source_alive && (!had_targets || !queued.targets.is_empty()) {
+            queued.action.resolve(self, queued.source, &queued.targets, queued.energy_spent);
         }
         
         self.remove_dead_enemies();
         
-        Ok(())
+        // Combat ends once everything in flight has resolved, so the card
+        // that struck the last blow still reaches its pile.
+        if self.action_queue.is_empty() {
+            self.check_combat_end();
+            // A full drain is always inside `apply`, which closes the input itself.
+            if !self.resolving_actions {
+                self.finish_input_record();
+            }
+        }
+        
+        Some(queued)
+    }
+    
+    /// Drains the action queue. Calls made while the queue is already being
+    /// drained return at once; the outer loop picks up what they queued.
+    pub fn resolve_actions(&mut self) {
+        if self.resolving_actions {
+            return;
+        }
+        
+        self.resolving_actions = true;
+        while self.step_action().is_some() {}
+        self.resolving_actions = false;
     }
     
     pub fn shuffle_draw_pile(&mut self) {
-        let mut rng = thread_rng();
-        self.draw_pile.shuffle(&mut rng);
+        let rng = self.rng.stream(RngStream::Shuffle);
+        self.draw_pile.to_mut().shuffle(rng);
+        self.fire_event(GameEvent::DrawPileShuffled);
     }
     
     pub fn draw_card(&mut self) -> Option<Card> {
@@ -380,7 +1192,9 @@ impl GameState {
     pub fn draw_cards(&mut self, count: usize) {
         for _ in 0..count {
             if let Some(card) = self.draw_card() {
+                let card_id = card.instance_id();
                 self.hand.push(card);
+                self.fire_event(GameEvent::CardDrawn { card: card_id });
             }
         }
     }
@@ -389,6 +1203,7 @@ impl GameState {
         if index < self.hand.len() {
             let card = self.hand.remove(index);
             self.discard_pile.push(card.clone());
+            self.fire_event(GameEvent::CardDiscarded { card: card.instance_id() });
             Some(card)
         } else {
             None
@@ -411,60 +1226,163 @@ impl GameState {
     }
     
     pub fn discard_hand(&mut self) {
+        let discarded: Vec<u32> = self.hand.iter().map(|card| card.instance_id()).collect();
         self.discard_pile.append(&mut self.hand);
+        
+        for card in discarded {
+            self.fire_event(GameEvent::CardDiscarded { card });
+        }
     }
     
     pub fn exhaust_from_hand(&mut self, index: usize) -> Option<Card> {
         if index < self.hand.len() {
             let card = self.hand.remove(index);
             self.exhaust_pile.push(card.clone());
+            self.fire_event(GameEvent::CardExhausted { card: card.instance_id() });
             Some(card)
         } else {
             None
         }
     }
     
+    /// The `add_card_to_*` functions put a new card into the combat and fire `CardCreated`.
     pub fn add_card_to_hand(&mut self, card: Card) {
+        let card_id = card.instance_id();
         self.hand.push(card);
+        self.fire_event(GameEvent::CardCreated { card: card_id, pile: CardPile::Hand });
     }
     
     pub fn add_card_to_discard(&mut self, card: Card) {
+        let card_id = card.instance_id();
         self.discard_pile.push(card);
+        self.fire_event(GameEvent::CardCreated { card: card_id, pile: CardPile::Discard });
     }
     
     pub fn add_card_to_exhaust(&mut self, card: Card) {
+        let card_id = card.instance_id();
         self.exhaust_pile.push(card);
+        self.fire_event(GameEvent::CardCreated { card: card_id, pile: CardPile::Exhaust });
     }
     
     pub fn add_card_to_draw_pile(&mut self, card: Card) {
+        let card_id = card.instance_id();
         self.draw_pile.push(card);
+        self.fire_event(GameEvent::CardCreated { card: card_id, pile: CardPile::Draw });
     }
     
+    /// Moves dead enemies from the field into the graveyard. Their ids stay
+    /// valid for lookups through `dead_enemy`.
     pub fn remove_dead_enemies(&mut self) {
-        self.enemies.retain(|enemy| enemy.is_alive());
+        let (living, dead): (Vec<_>, Vec<_>) = std::mem::take(&mut self.enemies)
+            .into_iter()
+            .partition(|(_, enemy)| enemy.is_alive());
+        
+        self.enemies = living;
+        for (id, _) in &dead {
+            self.positions.remove(id);
+        }
+        self.graveyard.extend(dead);
     }
     
-    pub fn execute_enemy_intent(&mut self, enemy_id: usize, intent: &Intent, targets: &[EntityId]) {
+    /// Brings a new enemy onto the field mid-combat at `slot`, or at the
+    /// leftmost free position. It gets its starting powers and picks its move
+    /// straight away, then fires `EnemySpawned`.
+    pub fn summon_enemy(&mut self, enemy: Box<dyn Enemy>, slot: Option<usize>) -> Result<EnemyId, String> {
+        if self.living_enemy_count() >= MAX_ENEMIES {
+            return Err(format!("No room for {}: {} enemies are already on the field", enemy.get_name(), MAX_ENEMIES));
+        }
+        let free = self.free_slots(0);
+        let slot = match slot {
+            Some(slot) if free.contains(&slot) => slot,
+            Some(slot) => return Err(format!("Slot {} is not free", slot)),
+            None => free[0],
+        };
+        Ok(self.place_enemy(enemy, slot))
+    }
+    
+    /// Replaces an enemy with `into`: the first takes its position and the rest
+    /// the nearest free ones, each starting at the splitter's current HP. Any
+    /// that do not fit under `MAX_ENEMIES` are left out. The splitter goes to
+    /// the graveyard with no HP, without firing any death events.
+    pub fn split_enemy(&mut self, enemy_id: EnemyId, into: Vec<Box<dyn Enemy>>) -> Vec<EnemyId> {
+        let Some(index) = self.enemies.iter().position(|(id, _)| *id == enemy_id) else {
+            return Vec::new();
+        };
+        let (_, mut splitter) = self.enemies.remove(index);
+        let slot = self.positions.remove(&enemy_id).unwrap_or(0);
+        let health = splitter.get_current_health();
+        splitter.set_health(0);
+        self.graveyard.push((enemy_id, splitter));
+        self.effects.retain(|entry| entry.owner != EntityId::Enemy(enemy_id));
+        
+        let mut spawned = Vec::new();
+        for mut enemy in into {
+            let Some(&free) = self.free_slots(slot).first() else {
+                break;
+            };
+            enemy.set_max_health(health);
+            enemy.set_health(health);
+            spawned.push(self.place_enemy(enemy, free));
+        }
+        spawned
+    }
+    
+    fn place_enemy(&mut self, enemy: Box<dyn Enemy>, slot: usize) -> EnemyId {
+        let id = self.allocate_enemy_id();
+        let index = self.enemies
+            .iter()
+            .position(|(other, _)| self.enemy_slot(*other).is_some_and(|other_slot| other_slot > slot))
+            .unwrap_or(self.enemies.len());
+        self.enemies.insert(index, (id, enemy));
+        self.positions.insert(id, slot);
+        
+        self.add_starting_powers(id);
+        let intent = self.choose_enemy_intent(id);
+        if let Some(enemy) = self.enemy_mut(id) {
+            enemy.set_intent(intent);
+        }
+        self.fire_event(GameEvent::EnemySpawned { enemy: EntityId::Enemy(id) });
+        id
+    }
+    
+    fn add_starting_powers(&mut self, enemy_id: EnemyId) {
+        let powers = self.enemy(enemy_id).map(|enemy| enemy.starting_powers()).unwrap_or_default();
+        for power in powers {
+            self.add_effect(EntityId::Enemy(enemy_id), power);
+        }
+    }
+    
+    pub fn execute_enemy_intent(&mut self, enemy_id: EnemyId, intent: &Intent, targets: &[EntityId]) {
         let source = EntityId::Enemy(enemy_id);
         
         intent.execute(self, source, targets, None);
-
+        
         self.fire_event(GameEvent::EnemyAction {
             enemy: source,
         });
     }
     
     pub fn execute_all_enemy_turns(&mut self) {
-        let enemy_count = self.enemies.len();
+        self.decay_statuses(EntityId::Player, DecayTiming::OpponentTurnStart);
         
-        for enemy_id in 0..enemy_count {
-            if !self.enemies[enemy_id].is_alive() {
+        for enemy_id in self.enemy_ids() {
+            let is_alive = self.enemy(enemy_id).is_some_and(|enemy| enemy.is_alive());
+            if !is_alive {
                 continue;
             }
             
             self.process_enemy_turn_start(enemy_id);
             
-            let intent = self.enemies[enemy_id].get_intent(self.turn_count);
+            let decided = match self.enemy_mut(enemy_id) {
+                Some(enemy) => enemy.intent().cloned(),
+                None => continue,
+            };
+            let Some(intent) = decided.or_else(|| self.choose_enemy_intent(enemy_id)) else {
+                continue;
+            };
+            if let Some(enemy) = self.enemy_mut(enemy_id) {
+                enemy.set_intent(None);
+            }
             let intent_desc = intent.description().to_string();
             
             self.record_enemy_intent(enemy_id, intent_desc);
@@ -474,20 +1392,45 @@ impl GameState {
             self.process_enemy_turn_end(enemy_id);
         }
         
+        self.decay_statuses(EntityId::Player, DecayTiming::OpponentTurnEnd);
         self.remove_dead_enemies();
         
         self.turn_count += 1;
+        self.check_combat_end();
+    }
+    
+    /// Opens the combat: gives every enemy its starting powers, fires
+    /// `CombatStarted` and begins the first player turn.
+    pub fn start_combat(&mut self) {
+        for enemy_id in self.enemy_ids() {
+            self.add_starting_powers(enemy_id);
+        }
+        self.fire_event(GameEvent::CombatStarted);
+        self.start_player_turn();
+    }
+    
+    /// Fires `CombatEnded` the first time the combat is found to be over.
+    fn check_combat_end(&mut self) {
+        if self.combat_ended || !self.is_combat_over() {
+            return;
+        }
+        
+        self.combat_ended = true;
+        self.fire_event(GameEvent::CombatEnded { victory: !self.is_player_dead() });
     }
     
     pub fn start_player_turn(&mut self) {
         if self.current_turn_record.turn_number > 0 || !self.current_turn_record.cards_played.is_empty() {
-            self.turn_history.push(self.current_turn_record.clone());
+            Rc::make_mut(&mut self.turn_history).push(self.current_turn_record.clone());
         }
         
         self.current_turn_record = TurnRecord::new(self.turn_count);
         
         self.apply_poison(EntityId::Player);
-        self.player.decay_debuffs();
+        self.decay_statuses(EntityId::Player, DecayTiming::OwnerTurnStart);
+        for enemy in self.get_all_living_enemies() {
+            self.decay_statuses(enemy, DecayTiming::OpponentTurnStart);
+        }
         
         if !self.player.has_modifier(&Modifier::RetainBlock) {
             self.player.set_block(0);
@@ -497,60 +1440,125 @@ impl GameState {
         self.player.reset_hero_ability();
         
         if let Some(upgraded_card) = self.pending_upgraded_card.take() {
-            self.hand.push(upgraded_card);
+            self.add_card_to_hand(upgraded_card);
         }
         
         let default_card_drawn_at_start_of_turn = 5;
         self.draw_cards(default_card_drawn_at_start_of_turn);
         
+        self.decide_enemy_intents();
+        
         self.fire_event(GameEvent::TurnStarted { entity: EntityId::Player });
+        self.check_combat_end();
     }
     
-    pub fn process_enemy_turn_start(&mut self, enemy_id: usize) {
+    /// Every living enemy without a decided move picks the one it will make
+    /// at the end of this player turn. A move set earlier, e.g. by Stun, is kept.
+    fn decide_enemy_intents(&mut self) {
+        for enemy_id in self.enemy_ids() {
+            let undecided = self.enemy(enemy_id).is_some_and(|enemy| enemy.is_alive() && enemy.intent().is_none());
+            if !undecided {
+                continue;
+            }
+            
+            let intent = self.choose_enemy_intent(enemy_id);
+            if let Some(enemy) = self.enemy_mut(enemy_id) {
+                enemy.set_intent(intent);
+            }
+        }
+    }
+    
+    fn choose_enemy_intent(&mut self, enemy_id: EnemyId) -> Option<Intent> {
+        let view = self.combat_view(enemy_id);
+        let (_, enemy) = self.enemies.iter_mut().find(|(id, _)| *id == enemy_id)?;
+        Some(enemy.choose_intent(&view, &mut self.rng))
+    }
+    
+    /// What `viewer` gets to see when it picks its move.
+    pub fn combat_view(&self, viewer: EnemyId) -> CombatView {
+        CombatView {
+            turn: self.turn_count,
+            viewer,
+            player: CombatantView::of(&self.player),
+            enemies: self.enemies
+                .iter()
+                .filter(|(_, enemy)| enemy.is_alive())
+                .map(|(id, enemy)| (*id, CombatantView::of(enemy.as_ref())))
+                .collect(),
+        }
+    }
+    
+    pub fn enemy_intent(&self, enemy_id: EnemyId) -> Option<&Intent> {
+        self.enemy(enemy_id).and_then(|enemy| enemy.intent())
+    }
+    
+    /// The enemy's decided move as it would hit the player right now.
+    pub fn enemy_intent_preview(&self, enemy_id: EnemyId) -> Option<IntentPreview> {
+        self.enemy_intent(enemy_id)
+            .map(|intent| intent.preview(self, EntityId::Enemy(enemy_id), EntityId::Player))
+    }
+    
+    /// Replaces an enemy's decided move, e.g. when it is stunned.
+    pub fn set_enemy_intent(&mut self, enemy_id: EnemyId, intent: Intent) {
+        if let Some(enemy) = self.enemy_mut(enemy_id) {
+            enemy.override_intent(intent);
+        }
+    }
+    
+    pub fn process_enemy_turn_start(&mut self, enemy_id: EnemyId) {
         self.apply_poison(EntityId::Enemy(enemy_id));
         
-        if let Some(enemy) = self.enemies.get_mut(enemy_id) {
+        if let Some(enemy) = self.enemy_mut(enemy_id) {
             if !enemy.has_modifier(&Modifier::RetainBlock) {
                 enemy.set_block(0);
             }
         }
+        
+        if self.is_entity_alive(EntityId::Enemy(enemy_id)) {
+            self.decay_statuses(EntityId::Enemy(enemy_id), DecayTiming::OwnerTurnStart);
+            self.fire_event(GameEvent::TurnStarted { entity: EntityId::Enemy(enemy_id) });
+        }
     }
     
-    pub fn process_enemy_turn_end(&mut self, enemy_id: usize) {
-        if let Some(enemy) = self.enemies.get_mut(enemy_id) {
-            enemy.decay_debuffs();
+    pub fn process_enemy_turn_end(&mut self, enemy_id: EnemyId) {
+        let entity = EntityId::Enemy(enemy_id);
+        if !self.is_entity_alive(entity) {
+            return;
         }
+        
+        self.fire_event(GameEvent::TurnEnded { entity });
+        self.gain_end_of_turn_block(entity);
+        self.decay_statuses(entity, DecayTiming::OwnerTurnEnd);
     }
     
+    /// Poison hits at the start of its owner's turn; it then decays like any other status.
     fn apply_poison(&mut self, entity: EntityId) {
-        let poison = self.get_status(entity, StatusType::Poison);
+        let poison = self.get_status(entity, StatusType::POISON);
         if poison > 0 {
-            self.modify_hp(entity, -poison);
-            
-            match entity {
-                EntityId::Player => {
-                    self.player.reduce_status(StatusType::Poison, 1);
-                }
-                EntityId::Enemy(id) => {
-                    if let Some(enemy) = self.enemies.get_mut(id) {
-                        enemy.reduce_status(StatusType::Poison, 1);
-                    }
-                }
-            }
+            self.apply_damage(DamageKind::Poison, entity, entity, poison);
         }
     }
     
     pub fn end_player_turn(&mut self) {
         self.fire_event(GameEvent::TurnEnded { entity: EntityId::Player });
+        self.gain_end_of_turn_block(EntityId::Player);
+        self.decay_statuses(EntityId::Player, DecayTiming::OwnerTurnEnd);
+        for enemy in self.get_all_living_enemies() {
+            self.decay_statuses(enemy, DecayTiming::OpponentTurnEnd);
+        }
         
-        if !self.player.has_modifier(&Modifier::RetainHand) {
+        if self.player.has_modifier(&Modifier::RetainHand) {
+            let retained: Vec<u32> = self.hand.iter().map(|card| card.instance_id()).collect();
+            for card in retained {
+                self.fire_event(GameEvent::CardRetained { card });
+            }
+        } else {
             self.discard_hand();
         }
     }
     
     pub fn start_enemy_phase(&mut self) {
-        let enemy_count = self.enemies.len();
-        for enemy_id in 0..enemy_count {
+        for enemy_id in self.enemy_ids() {
             self.process_enemy_turn_start(enemy_id);
         }
     }
@@ -564,7 +1572,7 @@ impl GameState {
     }
     
     pub fn are_all_enemies_dead(&self) -> bool {
-        self.enemies.iter().all(|e| !e.is_alive())
+        self.enemies.iter().all(|(_, e)| !e.is_alive())
     }
     
     pub fn is_combat_over(&self) -> bool {
@@ -572,13 +1580,102 @@ impl GameState {
     }
     
     pub fn living_enemy_count(&self) -> usize {
-        self.enemies.iter().filter(|e| e.is_alive()).count()
+        self.enemies.iter().filter(|(_, e)| e.is_alive()).count()
     }
     
-    pub fn use_hero_ability(&mut self) -> Result<(), String> {
-        const HERO_ABILITY_COST: i32 = 1;
-        const HERO_ABILITY_DAMAGE: i32 = 2;
+    /// Checks an action against the same rules `apply` enforces, without changing anything.
+    pub fn validate_action(&self, action: &PlayerAction) -> Result<(), String> {
+        if self.is_combat_over() {
+            return Err("Combat is over".to_string());
+        }
+        
+        if !self.action_queue.is_empty() {
+            return Err("Actions are still resolving".to_string());
+        }
+        
+        match *action {
+            PlayerAction::PlayCard { hand_index, target } => self.card_targets(hand_index, target).map(|_| ()),
+            PlayerAction::UseHeroAbility => self.check_hero_ability(),
+            PlayerAction::EndTurn => Ok(()),
+        }
+    }
+    
+    /// Every action the player may take right now. Single-target cards are listed
+    /// once per living enemy; other cards are listed with no target.
+    pub fn legal_actions(&self) -> Vec<PlayerAction> {
+        if self.is_combat_over() {
+            return Vec::new();
+        }
+        
+        let living = self.get_all_living_enemies();
+        let mut actions = Vec::new();
+        
+        for (hand_index, card) in self.hand.iter().enumerate() {
+            let candidates: Vec<Option<EntityId>> = match card.targeting() {
+                CardTargeting::SingleEnemy => living.iter().map(|id| Some(*id)).collect(),
+                _ => vec![None],
+            };
+            
+            for target in candidates {
+                let action = PlayerAction::PlayCard { hand_index, target };
+                if self.validate_action(&action).is_ok() {
+                    actions.push(action);
+                }
+            }
+        }
         
+        if self.check_hero_ability().is_ok() {
+            actions.push(PlayerAction::UseHeroAbility);
+        }
+        
+        actions.push(PlayerAction::EndTurn);
+        actions
+    }
+    
+    /// Performs a player action. Ending the turn also runs the enemy turns and,
+    /// if the combat continues, starts the next player turn.
+    pub fn apply(&mut self, action: PlayerAction) -> Result<(), String> {
+        self.validate_action(&action)?;
+        
+        if self.recording.is_some() {
+            let before = self.entity_states();
+            let turn = self.turn_count;
+            if let Some(recording) = &mut self.recording {
+                Rc::make_mut(recording).log.begin_input(turn, action, before);
+            }
+        }
+        
+        let result = match action {
+            PlayerAction::PlayCard { hand_index, target } => self.play_card(hand_index, target),
+            PlayerAction::UseHeroAbility => self.use_hero_ability(),
+            PlayerAction::EndTurn => {
+                self.resolve_actions();
+                self.end_player_turn();
+                self.execute_all_enemy_turns();
+                if !self.is_combat_over() {
+                    self.start_player_turn();
+                }
+                Ok(())
+            }
+        };
+        
+        if self.action_queue.is_empty() {
+            self.finish_input_record();
+        }
+        
+        result
+    }
+    
+    fn finish_input_record(&mut self) {
+        if self.recording.is_some() {
+            let after = self.entity_states();
+            if let Some(recording) = &mut self.recording {
+                Rc::make_mut(recording).log.finish_input(after);
+            }
+        }
+    }
+    
+    fn check_hero_ability(&self) -> Result<(), String> {
         if self.player.hero_ability_used() {
             return Err("Hero ability already used this turn".to_string());
         }
@@ -592,7 +1689,13 @@ impl GameState {
             return Err("No cards in hand to exhaust".to_string());
         }
         
-        self.player.spend_energy(HERO_ABILITY_COST);
+        Ok(())
+    }
+    
+    pub fn use_hero_ability(&mut self) -> Result<(), String> {
+        self.check_hero_ability()?;
+        
+        self.spend_energy(HERO_ABILITY_COST);
         self.player.use_hero_ability();
         
         let card_index = self.hand.len() - 1;
@@ -601,9 +1704,12 @@ impl GameState {
         let upgraded_card = (card.upgrade_fn())(card.instance_id());
         self.pending_upgraded_card = Some(upgraded_card);
         
+        let card_id = card.instance_id();
         self.exhaust_pile.push(card);
+        self.fire_event(GameEvent::CardExhausted { card: card_id });
         
-        self.modify_hp(EntityId::Player, -HERO_ABILITY_DAMAGE);
+        self.apply_damage(DamageKind::HpLoss, EntityId::Player, EntityId::Player, HERO_ABILITY_DAMAGE);
+        self.check_combat_end();
         
         Ok(())
     }
@@ -1,15 +1,31 @@
 pub mod action;
+pub mod action_queue;
 pub mod card;
+pub mod pile;
 pub mod enemy;
 pub mod player;
 pub mod base_state;
 pub mod game_state;
 pub mod effects;
+pub mod rng;
+pub mod registry;
+pub mod snapshot;
+pub mod player_action;
+pub mod combat_log;
+pub mod status;
+pub mod damage;
 
-pub use action::{Action, Intent};
+pub use action::{Action, Intent, IntentKind, IntentPreview};
+pub use action_queue::{ActionQueue, QueuedAction};
 pub use card::{CardTargeting};
 pub use effects::{Effect, EffectUIState};
-pub use game_state::{GameState, EntityId, GameEvent};
+pub use game_state::{GameState, EntityId, EnemyId, GameEvent, CardPile};
 pub use player::{Player, STSClass};
-pub use enemy::Enemy;
-pub use base_state::{StatusType, Modifier, State};
\ No newline at end of file
+pub use enemy::{Enemy, CombatView, CombatantView};
+pub use rng::{GameRng, RngStream};
+pub use registry::Registry;
+pub use player_action::PlayerAction;
+pub use combat_log::{CombatLog, CombatRecording, LogEntry, LogRecord};
+pub use base_state::{StatusType, Modifier, State};
+pub use damage::{DamageContext, DamageKind, DamageModifier, DamageResult, DamageStage};
+pub use status::{StatusDefinition, StatusKind, StatusRegistry, Stacking, DecayTiming};
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::ops::Deref;
+use std::rc::Rc;
+
+use crate::core::card::Card;
+
+/// An ordered pile of cards. Cloning a pile only bumps a reference count; the
+/// cards are copied the first time one of the clones is changed.
+#[derive(Clone, Debug, Default)]
+pub struct Pile {
+    cards: Rc<Vec<Card>>,
+}
+
+impl Pile {
+    pub fn new() -> Self {
+        Pile::default()
+    }
+    
+    pub fn to_mut(&mut self) -> &mut Vec<Card> {
+        Rc::make_mut(&mut self.cards)
+    }
+    
+    pub fn push(&mut self, card: Card) {
+        self.to_mut().push(card);
+    }
+    
+    pub fn pop(&mut self) -> Option<Card> {
+        if self.cards.is_empty() {
+            return None;
+        }
+        self.to_mut().pop()
+    }
+    
+    pub fn remove(&mut self, index: usize) -> Card {
+        self.to_mut().remove(index)
+    }
+    
+    /// Moves every card of `other` onto the top of this pile, leaving `other` empty.
+    pub fn append(&mut self, other: &mut Pile) {
+        let cards = Rc::unwrap_or_clone(std::mem::take(&mut other.cards));
+        self.to_mut().extend(cards);
+    }
+    
+    /// Whether both piles still point at the same, not yet copied, cards.
+    pub fn shares_cards_with(&self, other: &Pile) -> bool {
+        Rc::ptr_eq(&self.cards, &other.cards)
+    }
+}
+
+impl Deref for Pile {
+    type Target = [Card];
+    
+    fn deref(&self) -> &[Card] {
+        &self.cards
+    }
+}
+
+impl From<Vec<Card>> for Pile {
+    fn from(cards: Vec<Card>) -> Self {
+        Pile { cards: Rc::new(cards) }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::core::base_state::{BaseState, State, StatusType, Status, Modifier};
+use serde::{Serialize, Deserialize};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum STSClass {
     Ironclad,
     // Silent,
@@ -8,25 +9,33 @@ pub enum STSClass {
     // Watcher,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Player {
     class: STSClass,
     base_state: BaseState,
     max_energy: i32,
     energy: i32,
     hero_ability_used_this_turn: bool,
+    /// Share of every heal the player receives, e.g. 75 under a run rule that reduces healing.
+    #[serde(default = "full_healing")]
+    healing_percent: i32,
+}
+
+fn full_healing() -> i32 {
+    100
 }
 
 impl Player {
     pub fn new(class: STSClass, name: String, max_health: i32) -> Self {
         let initial_energy = 3;
-
+        
         Player {
             class: class,
             base_state: BaseState::new(name, max_health),
             max_energy: initial_energy,
             energy: initial_energy,
             hero_ability_used_this_turn: false,
+            healing_percent: full_healing(),
         }
     }
     
@@ -61,6 +70,36 @@ impl Player {
     pub fn reset_hero_ability(&mut self) {
         self.hero_ability_used_this_turn = false;
     }
+    
+    pub fn healing_percent(&self) -> i32 {
+        self.healing_percent
+    }
+    
+    pub fn set_healing_percent(&mut self, percent: i32) {
+        self.healing_percent = percent.max(0);
+    }
+    
+    /// What a heal of `amount` comes to after the healing percent, before capping at max HP.
+    pub fn healing_amount(&self, amount: i32) -> i32 {
+        (amount * self.healing_percent / 100).max(0)
+    }
+    
+    /// Heals outside of combat, e.g. at a rest site. Returns the HP actually restored.
+    pub fn heal(&mut self, amount: i32) -> i32 {
+        let before = self.get_current_health();
+        self.set_health(before + self.healing_amount(amount));
+        self.get_current_health() - before
+    }
+    
+    /// Raises max HP and heals by the same amount, which healing modifiers don't reduce.
+    pub fn raise_max_health(&mut self, amount: i32) {
+        self.set_max_health(self.get_max_health() + amount);
+        self.set_health(self.get_current_health() + amount);
+    }
+    
+    pub fn lower_max_health(&mut self, amount: i32) {
+        self.set_max_health(self.get_max_health() - amount);
+    }
 }
 
 impl State for Player {
@@ -112,6 +151,18 @@ impl State for Player {
         self.base_state.set_health(amount)
     }
     
+    fn set_max_health(&mut self, amount: i32) {
+        self.base_state.set_max_health(amount)
+    }
+    
+    fn get_temp_hp(&self) -> i32 {
+        self.base_state.get_temp_hp()
+    }
+    
+    fn set_temp_hp(&mut self, amount: i32) {
+        self.base_state.set_temp_hp(amount)
+    }
+    
     fn has_modifier(&self, modifier: &Modifier) -> bool {
         self.base_state.has_modifier(modifier)
     }
@@ -128,10 +179,6 @@ impl State for Player {
         self.base_state.remove_expired_statuses()
     }
     
-    fn decay_debuffs(&mut self) {
-        self.base_state.decay_debuffs()
-    }
-    
     fn clear_all_statuses(&mut self) {
         self.base_state.clear_all_statuses()
     }
@@ -0,0 +1,11 @@
+use serde::{Serialize, Deserialize};
+
+use crate::core::game_state::EntityId;
+
+/// A single decision the player can make during their turn.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PlayerAction {
+    PlayCard { hand_index: usize, target: Option<EntityId> },
+    UseHeroAbility,
+    EndTurn,
+}
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::core::card::Card;
+use crate::core::damage::DamageModifier;
+use crate::core::effects::Effect;
+use crate::core::enemy::Enemy;
+
+pub type CardFactory = fn(u32, bool) -> Card;
+pub type EffectLoader = fn(&Value) -> Result<Box<dyn Effect>, String>;
+pub type DamageModifierLoader = fn(&Value) -> Result<Rc<dyn DamageModifier>, String>;
+pub type EnemyFactory = Rc<dyn Fn() -> Box<dyn Enemy>>;
+pub type EnemyLoader = fn(&Value) -> Result<Box<dyn Enemy>, String>;
+
+/// Lookup tables that rebuild cards, effects, damage modifiers and enemies
+/// from their string ids.
+/// Trait objects and function pointers cannot be serialized, so saves only
+/// store ids plus plain state and go through here on load.
+#[derive(Default)]
+pub struct Registry {
+    cards: HashMap<String, CardFactory>,
+    effects: HashMap<String, EffectLoader>,
+    damage_modifiers: HashMap<String, DamageModifierLoader>,
+    enemies: HashMap<String, (EnemyFactory, EnemyLoader)>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn register_card(&mut self, id: &str, factory: CardFactory) {
+        self.cards.insert(id.to_string(), factory);
+    }
+    
+    pub fn register_effect(&mut self, id: &str, loader: EffectLoader) {
+        self.effects.insert(id.to_string(), loader);
+    }
+    
+    pub fn register_damage_modifier(&mut self, id: &str, loader: DamageModifierLoader) {
+        self.damage_modifiers.insert(id.to_string(), loader);
+    }
+    
+    pub fn register_enemy(&mut self, id: &str, factory: impl Fn() -> Box<dyn Enemy> + 'static, loader: EnemyLoader) {
+        self.enemies.insert(id.to_string(), (Rc::new(factory), loader));
+    }
+    
+    pub fn create_card(&self, id: &str, instance_id: u32, upgraded: bool) -> Result<Card, String> {
+        let factory = self.cards
+            .get(id)
+            .ok_or_else(|| format!("Unknown card id: {}", id))?;
+        Ok(factory(instance_id, upgraded))
+    }
+    
+    pub fn load_effect(&self, id: &str, state: &Value) -> Result<Box<dyn Effect>, String> {
+        let loader = self.effects
+            .get(id)
+            .ok_or_else(|| format!("Unknown effect id: {}", id))?;
+        loader(state)
+    }
+    
+    pub fn load_damage_modifier(&self, id: &str, state: &Value) -> Result<Rc<dyn DamageModifier>, String> {
+        let loader = self.damage_modifiers
+            .get(id)
+            .ok_or_else(|| format!("Unknown damage modifier id: {}", id))?;
+        loader(state)
+    }
+    
+    /// Creates a fresh enemy at full health, as it appears at combat start.
+    pub fn create_enemy(&self, id: &str) -> Result<Box<dyn Enemy>, String> {
+        let (factory, _) = self.enemies
+            .get(id)
+            .ok_or_else(|| format!("Unknown enemy id: {}", id))?;
+        Ok(factory())
+    }
+    
+    pub fn load_enemy(&self, id: &str, state: &Value) -> Result<Box<dyn Enemy>, String> {
+        let (_, loader) = self.enemies
+            .get(id)
+            .ok_or_else(|| format!("Unknown enemy id: {}", id))?;
+        loader(state)
+    }
+    
+    pub fn has_card(&self, id: &str) -> bool {
+        self.cards.contains_key(id)
+    }
+    
+    pub fn card_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.cards.keys().map(|id| id.as_str()).collect();
+        ids.sort();
+        ids
+    }
+    
+    pub fn enemy_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.enemies.keys().map(|id| id.as_str()).collect();
+        ids.sort();
+        ids
+    }
+}
+
+/// Loader for effects whose whole state is their serde representation.
+pub fn load_effect<T>(state: &Value) -> Result<Box<dyn Effect>, String>
+where
+    T: Effect + DeserializeOwned + 'static,
+{
+    serde_json::from_value::<T>(state.clone())
+        .map(|effect| Box::new(effect) as Box<dyn Effect>)
+        .map_err(|e| e.to_string())
+}
+
+/// Loader for damage modifiers whose whole state is their serde representation.
+pub fn load_damage_modifier<T>(state: &Value) -> Result<Rc<dyn DamageModifier>, String>
+where
+    T: DamageModifier + DeserializeOwned + 'static,
+{
+    serde_json::from_value::<T>(state.clone())
+        .map(|modifier| Rc::new(modifier) as Rc<dyn DamageModifier>)
+        .map_err(|e| e.to_string())
+}
+
+/// Loader for enemies whose whole state is their serde representation.
+pub fn load_enemy<T>(state: &Value) -> Result<Box<dyn Enemy>, String>
+where
+    T: Enemy + DeserializeOwned + 'static,
+{
+    serde_json::from_value::<T>(state.clone())
+        .map(|enemy| Box::new(enemy) as Box<dyn Enemy>)
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,70 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use serde::{Serialize, Deserialize};
+
+/// Independent random streams derived from a single run seed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RngStream {
+    Shuffle,
+    CardRewards,
+    EnemyAi,
+    MapGeneration,
+}
+
+impl RngStream {
+    fn index(self) -> u64 {
+        match self {
+            RngStream::Shuffle => 0,
+            RngStream::CardRewards => 1,
+            RngStream::EnemyAi => 2,
+            RngStream::MapGeneration => 3,
+        }
+    }
+}
+
+/// Seeded random number generators for a run. Every stream starts from the
+/// same seed but advances on its own, so rolling a card reward never changes
+/// the next shuffle.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameRng {
+    seed: u64,
+    shuffle: ChaCha8Rng,
+    card_rewards: ChaCha8Rng,
+    enemy_ai: ChaCha8Rng,
+    map_generation: ChaCha8Rng,
+}
+
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        GameRng {
+            seed,
+            shuffle: Self::derive_stream(seed, RngStream::Shuffle),
+            card_rewards: Self::derive_stream(seed, RngStream::CardRewards),
+            enemy_ai: Self::derive_stream(seed, RngStream::EnemyAi),
+            map_generation: Self::derive_stream(seed, RngStream::MapGeneration),
+        }
+    }
+    
+    pub fn from_entropy() -> Self {
+        Self::new(rand::thread_rng().r#gen())
+    }
+    
+    fn derive_stream(seed: u64, stream: RngStream) -> ChaCha8Rng {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        rng.set_stream(stream.index());
+        rng
+    }
+    
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    
+    pub fn stream(&mut self, stream: RngStream) -> &mut ChaCha8Rng {
+        match stream {
+            RngStream::Shuffle => &mut self.shuffle,
+            RngStream::CardRewards => &mut self.card_rewards,
+            RngStream::EnemyAi => &mut self.enemy_ai,
+            RngStream::MapGeneration => &mut self.map_generation,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+
+use crate::core::card::Card;
+use crate::core::game_state::{EnemyId, EntityId, TurnRecord};
+use crate::core::player::Player;
+use crate::core::registry::Registry;
+use crate::core::rng::GameRng;
+use crate::core::status::StatusDefinition;
+
+/// A card reduced to what is needed to rebuild it through the registry.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CardSnapshot {
+    pub id: String,
+    pub instance_id: u32,
+    pub upgraded: bool,
+    pub cost_reduction: i32,
+}
+
+impl CardSnapshot {
+    pub fn from_card(card: &Card) -> Self {
+        CardSnapshot {
+            id: card.id().to_string(),
+            instance_id: card.instance_id(),
+            upgraded: card.is_upgraded(),
+            cost_reduction: card.cost_reduction(),
+        }
+    }
+    
+    pub fn restore(&self, registry: &Registry) -> Result<Card, String> {
+        let mut card = registry.create_card(&self.id, self.instance_id, self.upgraded)?;
+        card.reduce_cost(self.cost_reduction);
+        Ok(card)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EffectSnapshot {
+    pub owner: EntityId,
+    pub id: String,
+    pub state: Value,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DamageModifierSnapshot {
+    pub id: String,
+    pub state: Value,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EnemySnapshot {
+    pub enemy_id: EnemyId,
+    pub id: String,
+    pub state: Value,
+    /// Position on the field; older saves and the graveyard have none.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot: Option<usize>,
+}
+
+/// Plain data copy of a `GameState`, see `GameState::snapshot`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GameStateSnapshot {
+    pub player: Player,
+    pub enemies: Vec<EnemySnapshot>,
+    pub graveyard: Vec<EnemySnapshot>,
+    pub next_enemy_id: usize,
+    pub effects: Vec<EffectSnapshot>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub damage_modifiers: Vec<DamageModifierSnapshot>,
+    /// Only kept when the combat replaced the built-in status definitions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub statuses: Option<Vec<StatusDefinition>>,
+    
+    pub draw_pile: Vec<CardSnapshot>,
+    pub hand: Vec<CardSnapshot>,
+    pub discard_pile: Vec<CardSnapshot>,
+    pub exhaust_pile: Vec<CardSnapshot>,
+    
+    pub turn_history: Vec<TurnRecord>,
+    pub current_turn_record: TurnRecord,
+    pub turn_count: usize,
+    
+    pub pending_upgraded_card: Option<CardSnapshot>,
+    
+    pub rng: GameRng,
+    #[serde(default)]
+    pub combat_ended: bool,
+}
+
+pub fn snapshot_cards(cards: &[Card]) -> Vec<CardSnapshot> {
+    cards.iter().map(CardSnapshot::from_card).collect()
+}
+
+pub fn restore_cards(cards: &[CardSnapshot], registry: &Registry) -> Result<Vec<Card>, String> {
+    cards.iter().map(|card| card.restore(registry)).collect()
+}
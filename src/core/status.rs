@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+use serde::{Serialize, Deserialize};
+
+use crate::core::base_state::StatusType;
+use crate::core::damage::{DamageOperation, StatusDamageModifier};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusKind {
+    Buff,
+    Debuff,
+}
+
+/// What the stacks of a status count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stacking {
+    /// How strong the status is. Decaying clears it entirely.
+    Intensity,
+    /// How many turns are left. Decaying takes off one stack.
+    Duration,
+    /// Charges used up one at a time, such as Artifact. Decaying takes off one stack.
+    Counter,
+}
+
+/// When a status decays, relative to the turn of the entity that has it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DecayTiming {
+    Never,
+    OwnerTurnStart,
+    OwnerTurnEnd,
+    OpponentTurnStart,
+    OpponentTurnEnd,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StatusDefinition {
+    pub id: StatusType,
+    pub name: String,
+    pub kind: StatusKind,
+    pub stacking: Stacking,
+    pub decay: DecayTiming,
+    pub allows_negative: bool,
+    /// `{}` is replaced by the current stacks.
+    pub description: String,
+    #[serde(default)]
+    pub damage_modifier: Option<StatusDamageModifier>,
+    /// Status the owner gains as many stacks of as this one loses when it decays.
+    #[serde(default)]
+    pub decays_into: Option<StatusType>,
+    /// Changes the block its owner gains. Additions apply before multipliers and caps.
+    #[serde(default)]
+    pub block_modifier: Option<DamageOperation>,
+}
+
+impl StatusDefinition {
+    pub fn is_debuff(&self) -> bool {
+        self.kind == StatusKind::Debuff
+    }
+    
+    pub fn describe(&self, stacks: i32) -> String {
+        self.description.replace("{}", &stacks.to_string())
+    }
+}
+
+/// Definitions of every status a combat knows about, keyed by id.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct StatusRegistry {
+    definitions: HashMap<StatusType, StatusDefinition>,
+}
+
+impl StatusRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let definitions: Vec<StatusDefinition> = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid status definitions: {}", e))?;
+        
+        let mut registry = StatusRegistry::new();
+        for definition in definitions {
+            registry.register(definition);
+        }
+        Ok(registry)
+    }
+    
+    /// The statuses shipped with the game, from `assets/statuses/data/statuses.json`.
+    pub fn builtin() -> Self {
+        static BUILTIN: OnceLock<StatusRegistry> = OnceLock::new();
+        BUILTIN
+            .get_or_init(|| {
+                StatusRegistry::from_json(include_str!("../../assets/statuses/data/statuses.json"))
+                    .expect("Built-in status definitions are valid")
+            })
+            .clone()
+    }
+    
+    pub fn register(&mut self, definition: StatusDefinition) {
+        self.definitions.insert(definition.id.clone(), definition);
+    }
+    
+    pub fn get(&self, status: &StatusType) -> Option<&StatusDefinition> {
+        self.definitions.get(status)
+    }
+    
+    pub fn definitions(&self) -> impl Iterator<Item = &StatusDefinition> {
+        self.definitions.values()
+    }
+}
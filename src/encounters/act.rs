@@ -0,0 +1,131 @@
+use std::fs;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::core::rng::{GameRng, RngStream};
+use crate::encounters::EncounterDefinition;
+
+/// How many recent encounters a new pick may not repeat.
+const NO_REPEAT_WINDOW: usize = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EncounterTier {
+    Weak,
+    Strong,
+    Elite,
+    Boss,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct WeightedEncounter {
+    pub encounter: String,
+    pub weight: u32,
+}
+
+/// The encounter pools of one act, from `assets/encounters/acts/act_<n>.json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ActEncounters {
+    pub act: u32,
+    /// Hallway fights drawn from the weak pool before switching to the strong one.
+    pub weak_fights: usize,
+    pub weak: Vec<WeightedEncounter>,
+    pub strong: Vec<WeightedEncounter>,
+    pub elite: Vec<WeightedEncounter>,
+    pub boss: Vec<WeightedEncounter>,
+}
+
+/// What the run has fought so far in the current act.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EncounterHistory {
+    pub fought: Vec<String>,
+    pub hallway_fights: usize,
+}
+
+impl EncounterHistory {
+    pub fn record(&mut self, tier: EncounterTier, encounter: &str) {
+        if matches!(tier, EncounterTier::Weak | EncounterTier::Strong) {
+            self.hallway_fights += 1;
+        }
+        self.fought.push(encounter.to_string());
+    }
+    
+    /// The encounters a new pick has to avoid.
+    pub fn recent(&self) -> &[String] {
+        &self.fought[self.fought.len().saturating_sub(NO_REPEAT_WINDOW)..]
+    }
+}
+
+impl ActEncounters {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let act: ActEncounters = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid act encounters: {}", e))?;
+        
+        for tier in [EncounterTier::Weak, EncounterTier::Strong, EncounterTier::Elite, EncounterTier::Boss] {
+            if act.pool(tier).iter().all(|entry| entry.weight == 0) {
+                return Err(format!("Act {} has no {:?} encounters", act.act, tier));
+            }
+        }
+        Ok(act)
+    }
+    
+    /// Reads `assets/encounters/acts/act_<act>.json`.
+    pub fn load(act: u32) -> Result<Self, String> {
+        let path = format!("assets/encounters/acts/act_{}.json", act);
+        let json = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Self::from_json(&json)
+    }
+    
+    pub fn pool(&self, tier: EncounterTier) -> &[WeightedEncounter] {
+        match tier {
+            EncounterTier::Weak => &self.weak,
+            EncounterTier::Strong => &self.strong,
+            EncounterTier::Elite => &self.elite,
+            EncounterTier::Boss => &self.boss,
+        }
+    }
+    
+    /// Tier of the next hallway fight.
+    pub fn hallway_tier(&self, history: &EncounterHistory) -> EncounterTier {
+        if history.hallway_fights < self.weak_fights {
+            EncounterTier::Weak
+        } else {
+            EncounterTier::Strong
+        }
+    }
+    
+    /// Draws an encounter id from the pool by weight, skipping the last two
+    /// encounters fought unless nothing else is left. Rolls come from the map
+    /// generation stream, since the encounters are part of the act's layout.
+    pub fn pick(&self, tier: EncounterTier, history: &EncounterHistory, rng: &mut GameRng) -> &str {
+        let pool = self.pool(tier);
+        let fresh: Vec<&WeightedEncounter> = pool
+            .iter()
+            .filter(|entry| entry.weight > 0 && !history.recent().contains(&entry.encounter))
+            .collect();
+        let choices = if fresh.is_empty() { pool.iter().filter(|entry| entry.weight > 0).collect() } else { fresh };
+        
+        let total: u32 = choices.iter().map(|entry| entry.weight).sum();
+        let mut roll = rng.stream(RngStream::MapGeneration).gen_range(0..total);
+        for choice in &choices {
+            if roll < choice.weight {
+                return &choice.encounter;
+            }
+            roll -= choice.weight;
+        }
+        &choices[0].encounter
+    }
+    
+    /// Picks the next encounter of `tier`, loads its definition and records it.
+    /// If the definition fails to load, neither `history` nor `rng` changes.
+    pub fn next_encounter(&self, tier: EncounterTier, history: &mut EncounterHistory, rng: &mut GameRng) -> Result<EncounterDefinition, String> {
+        let mut rolled = rng.clone();
+        let id = self.pick(tier, history, &mut rolled).to_string();
+        let encounter = EncounterDefinition::load(&id)?;
+        
+        history.record(tier, &id);
+        *rng = rolled;
+        Ok(encounter)
+    }
+}
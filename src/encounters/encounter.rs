@@ -0,0 +1,81 @@
+use std::fs;
+use serde::Deserialize;
+
+use crate::core::enemy::Enemy;
+use crate::core::game_state::MAX_ENEMIES;
+use crate::core::registry::Registry;
+
+/// One fight, as written in `assets/encounters/data/<id>.json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EncounterDefinition {
+    #[serde(skip)]
+    pub id: String,
+    pub name: String,
+    pub enemies: Vec<EncounterEnemy>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EncounterEnemy {
+    pub id: String,
+    /// Field position; defaults to its place in the list.
+    #[serde(default)]
+    pub slot: Option<usize>,
+    /// Replaces the max HP from the enemy's own data file.
+    #[serde(default)]
+    pub max_health: Option<i32>,
+}
+
+impl EncounterDefinition {
+    pub fn from_json(id: &str, json: &str) -> Result<Self, String> {
+        let mut definition: EncounterDefinition = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid encounter {}: {}", id, e))?;
+        definition.id = id.to_string();
+        if definition.enemies.is_empty() {
+            return Err(format!("Encounter {} has no enemies", id));
+        }
+        if definition.enemies.len() > MAX_ENEMIES {
+            return Err(format!("Encounter {} has more than {} enemies", id, MAX_ENEMIES));
+        }
+        
+        let slots = definition.slots();
+        if slots.iter().any(|slot| *slot >= MAX_ENEMIES) {
+            return Err(format!("Encounter {} puts an enemy past slot {}", id, MAX_ENEMIES - 1));
+        }
+        if (1..slots.len()).any(|i| slots[..i].contains(&slots[i])) {
+            return Err(format!("Encounter {} puts two enemies in the same slot", id));
+        }
+        Ok(definition)
+    }
+    
+    /// Reads `assets/encounters/data/<id>.json`.
+    pub fn load(id: &str) -> Result<Self, String> {
+        let path = format!("assets/encounters/data/{}.json", id);
+        let json = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Self::from_json(id, &json)
+    }
+    
+    /// Field position of each enemy, in the order of `enemies`.
+    pub fn slots(&self) -> Vec<usize> {
+        self.enemies
+            .iter()
+            .enumerate()
+            .map(|(index, enemy)| enemy.slot.unwrap_or(index))
+            .collect()
+    }
+    
+    /// Creates the enemies through `registry`, with their HP overrides applied.
+    pub fn create_enemies(&self, registry: &Registry) -> Result<Vec<Box<dyn Enemy>>, String> {
+        self.enemies
+            .iter()
+            .map(|spec| {
+                let mut enemy = registry.create_enemy(&spec.id)?;
+                if let Some(max_health) = spec.max_health {
+                    enemy.set_max_health(max_health);
+                    enemy.set_health(max_health);
+                }
+                Ok(enemy)
+            })
+            .collect()
+    }
+}
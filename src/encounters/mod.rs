@@ -0,0 +1,5 @@
+pub mod encounter;
+pub mod act;
+
+pub use encounter::{EncounterDefinition, EncounterEnemy};
+pub use act::{ActEncounters, EncounterHistory, EncounterTier, WeightedEncounter};
@@ -0,0 +1,322 @@
+use std::rc::Rc;
+use rand::Rng;
+use serde::{Serialize, Deserialize};
+
+use crate::core::action::Intent;
+use crate::core::base_state::{State, StatusType, Status, Modifier};
+use crate::core::effects::Effect;
+use crate::core::enemy::{BaseEnemy, CombatView, Enemy};
+use crate::core::rng::{GameRng, RngStream};
+use crate::enemies::move_set::{EnemyDefinition, MoveCondition, MovePattern};
+
+/// What a data enemy remembers between moves.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiState {
+    pub history: Vec<String>,
+    pub cycle_index: usize,
+    pub used_once: Vec<String>,
+    pub phase: Option<String>,
+    pub turns_in_phase: usize,
+    /// Health when it last picked a move.
+    pub last_seen_health: Option<i32>,
+}
+
+/// An enemy whose stats and moves all come from its data file.
+#[derive(Clone)]
+pub struct DataEnemy {
+    definition: Rc<EnemyDefinition>,
+    base: BaseEnemy,
+    ai: AiState,
+    /// The AI state from before it picked its current intent, put back if that
+    /// intent is replaced.
+    ai_before_intent: Option<AiState>,
+}
+
+/// What a save keeps of a `DataEnemy`; the moves are read again from the data file.
+#[derive(Serialize, Deserialize)]
+struct DataEnemyState {
+    id: String,
+    base: BaseEnemy,
+    ai: AiState,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ai_before_intent: Option<AiState>,
+    next_move: Option<String>,
+}
+
+impl DataEnemy {
+    /// Panics if the data file is missing or invalid, like the card constructors.
+    pub fn new(id: &str) -> Self {
+        let definition = EnemyDefinition::load(id).unwrap_or_else(|e| panic!("{}", e));
+        Self::from_definition(definition)
+    }
+    
+    pub fn from_definition(definition: EnemyDefinition) -> Self {
+        Self::from_shared(Rc::new(definition))
+    }
+    
+    pub fn from_shared(definition: Rc<EnemyDefinition>) -> Self {
+        DataEnemy {
+            base: BaseEnemy::new(definition.id.clone(), definition.name.clone(), definition.max_health),
+            ai: AiState {
+                phase: definition.ai.initial_phase.clone(),
+                ..AiState::default()
+            },
+            ai_before_intent: None,
+            definition,
+        }
+    }
+    
+    pub fn from_state(state: &serde_json::Value) -> Result<Box<dyn Enemy>, String> {
+        let state: DataEnemyState = serde_json::from_value(state.clone()).map_err(|e| e.to_string())?;
+        let definition = EnemyDefinition::load(&state.id)?;
+        
+        let mut base = state.base;
+        base.set_intent(state.next_move.and_then(|name| definition.intent(&name)));
+        
+        Ok(Box::new(DataEnemy {
+            definition: Rc::new(definition),
+            base,
+            ai: state.ai,
+            ai_before_intent: state.ai_before_intent,
+        }))
+    }
+    
+    pub fn definition(&self) -> &EnemyDefinition {
+        &self.definition
+    }
+    
+    pub fn ai_state(&self) -> &AiState {
+        &self.ai
+    }
+    
+    fn condition_holds(&self, condition: &MoveCondition, view: &CombatView) -> bool {
+        match condition {
+            MoveCondition::HealthBelowPercent(percent) => {
+                self.get_current_health() * 100 < self.get_max_health() * percent
+            }
+            MoveCondition::AllyHealthBelowPercent(percent) => {
+                view.allies().any(|(_, ally)| ally.health_percent() < *percent)
+            }
+            MoveCondition::PlayerBlockAtLeast(block) => view.player.block >= *block,
+            MoveCondition::Damaged => {
+                self.ai.last_seen_health.is_some_and(|health| self.get_current_health() < health)
+            }
+            MoveCondition::Phase(phase) => self.ai.phase.as_ref() == Some(phase),
+            MoveCondition::TurnsInPhaseAtLeast(turns) => self.ai.turns_in_phase >= *turns,
+            MoveCondition::LastMove(name) => self.ai.history.last() == Some(name),
+            MoveCondition::All(conditions) => conditions.iter().all(|c| self.condition_holds(c, view)),
+            MoveCondition::Any(conditions) => conditions.iter().any(|c| self.condition_holds(c, view)),
+        }
+    }
+    
+    /// How many times `name` was picked in a row most recently.
+    fn streak(&self, name: &str) -> usize {
+        self.ai.history.iter().rev().take_while(|m| *m == name).count()
+    }
+    
+    fn select_move(&mut self, view: &CombatView, rng: &mut GameRng) -> String {
+        let definition = Rc::clone(&self.definition);
+        let ai = &definition.ai;
+        
+        if let Some(name) = ai.opening.get(self.ai.history.len()) {
+            return name.clone();
+        }
+        
+        for conditional in &ai.conditional {
+            let spent = conditional.once && self.ai.used_once.contains(&conditional.name);
+            if !spent && self.condition_holds(&conditional.when, view) {
+                if conditional.once {
+                    self.ai.used_once.push(conditional.name.clone());
+                }
+                return conditional.name.clone();
+            }
+        }
+        
+        match &ai.pattern {
+            MovePattern::Cycle { moves } => {
+                let name = moves[self.ai.cycle_index % moves.len()].clone();
+                self.ai.cycle_index += 1;
+                name
+            }
+            MovePattern::Weighted { moves } => {
+                let allowed: Vec<_> = moves
+                    .iter()
+                    .filter(|m| m.max_in_a_row.is_none_or(|max| self.streak(&m.name) < max))
+                    .collect();
+                let choices = if allowed.is_empty() { moves.iter().collect() } else { allowed };
+                
+                let total: u32 = choices.iter().map(|m| m.weight).sum();
+                let mut roll = rng.stream(RngStream::EnemyAi).gen_range(0..total.max(1));
+                for choice in &choices {
+                    if roll < choice.weight {
+                        return choice.name.clone();
+                    }
+                    roll -= choice.weight;
+                }
+                choices[0].name.clone()
+            }
+        }
+    }
+}
+
+impl Enemy for DataEnemy {
+    fn choose_intent(&mut self, view: &CombatView, rng: &mut GameRng) -> Intent {
+        self.ai_before_intent = Some(self.ai.clone());
+        let name = self.select_move(view, rng);
+        self.ai.history.push(name.clone());
+        self.ai.last_seen_health = Some(self.get_current_health());
+        
+        self.ai.turns_in_phase += 1;
+        if let Some(phase) = self.definition.moves.get(&name).and_then(|m| m.next_phase.clone()) {
+            self.ai.phase = Some(phase);
+            self.ai.turns_in_phase = 0;
+        }
+        
+        self.definition.intent(&name).expect("Enemy definitions are validated on load")
+    }
+    
+    fn move_history(&self) -> &[String] {
+        &self.ai.history
+    }
+    
+    fn move_intent(&self, name: &str) -> Option<Intent> {
+        self.definition.intent(name)
+    }
+    
+    fn starting_powers(&self) -> Vec<Box<dyn Effect>> {
+        self.definition.powers.iter().map(|power| power.build()).collect()
+    }
+    
+    fn art_key(&self) -> Option<&str> {
+        self.definition.art.as_deref()
+    }
+    
+    fn intent(&self) -> Option<&Intent> {
+        self.base.intent()
+    }
+    
+    fn set_intent(&mut self, intent: Option<Intent>) {
+        self.base.set_intent(intent);
+    }
+    
+    /// The forced move takes the place of the replaced one in the history, and
+    /// the AI picks up again as if the replaced move had never been chosen.
+    fn override_intent(&mut self, intent: Intent) {
+        let name = intent.move_name().unwrap_or(intent.description()).to_string();
+        if self.base.intent().is_some() {
+            match self.ai_before_intent.take() {
+                Some(ai) => self.ai = ai,
+                None => {
+                    self.ai.history.pop();
+                }
+            }
+        }
+        self.ai.history.push(name);
+        self.base.set_intent(Some(intent));
+    }
+    
+    fn id(&self) -> &str {
+        self.base.id()
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        let state = DataEnemyState {
+            id: self.definition.id.clone(),
+            base: self.base.clone(),
+            ai: self.ai.clone(),
+            ai_before_intent: self.ai_before_intent.clone(),
+            next_move: self.base.intent().and_then(|intent| intent.move_name()).map(str::to_string),
+        };
+        serde_json::to_value(state).expect("DataEnemy is always serializable")
+    }
+    
+    fn clone_box(&self) -> Box<dyn Enemy> {
+        Box::new(self.clone())
+    }
+}
+
+impl State for DataEnemy {
+    fn get_name(&self) -> &str {
+        self.base.get_name()
+    }
+    
+    fn get_max_health(&self) -> i32 {
+        self.base.get_max_health()
+    }
+    
+    fn get_current_health(&self) -> i32 {
+        self.base.get_current_health()
+    }
+    
+    fn get_block(&self) -> i32 {
+        self.base.get_block()
+    }
+    
+    fn is_alive(&self) -> bool {
+        self.base.is_alive()
+    }
+    
+    fn get_status(&self, status_type: &StatusType) -> i32 {
+        self.base.get_status(status_type)
+    }
+    
+    fn get_all_statuses(&self) -> &Vec<Status> {
+        self.base.get_all_statuses()
+    }
+    
+    fn get_all_statuses_mut(&mut self) -> &mut Vec<Status> {
+        self.base.get_all_statuses_mut()
+    }
+    
+    fn add_status(&mut self, status_type: StatusType, stacks: i32) {
+        self.base.add_status(status_type, stacks)
+    }
+    
+    fn reduce_status(&mut self, status_type: StatusType, amount: i32) {
+        self.base.reduce_status(status_type, amount)
+    }
+    
+    fn set_block(&mut self, amount: i32) {
+        self.base.set_block(amount)
+    }
+    
+    fn set_health(&mut self, amount: i32) {
+        self.base.set_health(amount)
+    }
+    
+    fn set_max_health(&mut self, amount: i32) {
+        self.base.set_max_health(amount)
+    }
+    
+    fn get_temp_hp(&self) -> i32 {
+        self.base.get_temp_hp()
+    }
+    
+    fn set_temp_hp(&mut self, amount: i32) {
+        self.base.set_temp_hp(amount)
+    }
+    
+    fn has_modifier(&self, modifier: &Modifier) -> bool {
+        self.base.has_modifier(modifier)
+    }
+    
+    fn add_modifier(&mut self, modifier: Modifier) {
+        self.base.add_modifier(modifier)
+    }
+    
+    fn remove_modifier(&mut self, modifier: &Modifier) {
+        self.base.remove_modifier(modifier)
+    }
+    
+    fn remove_expired_statuses(&mut self) {
+        self.base.remove_expired_statuses()
+    }
+    
+    fn clear_all_statuses(&mut self) {
+        self.base.clear_all_statuses()
+    }
+    
+    fn clear_all_modifiers(&mut self) {
+        self.base.clear_all_modifiers()
+    }
+}
@@ -1,3 +1,9 @@
-pub mod dragonling;
+pub mod move_set;
+pub mod data_enemy;
+pub mod registry;
+pub mod powers;
 
-pub use dragonling::Dragonling;
\ No newline at end of file
+pub use move_set::{EnemyDefinition, MoveDefinition, MoveSelection, MovePattern, MoveCondition, PowerSpec};
+pub use data_enemy::{AiState, DataEnemy};
+pub use registry::{data_enemy_ids, register_enemies, register_powers};
+pub use powers::{CurlUp, Enrage, Split, SplitAction};
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map::Entry;
+use std::fs;
+use std::rc::Rc;
+use serde::Deserialize;
+
+use crate::cards::card_effects::{ApplyEffect, ApplyStatusAction, BlockEffect, DamageEffect, GainStatusAction, Ritual};
+use crate::core::action::{Action, Intent, IntentKind};
+use crate::core::base_state::StatusType;
+use crate::core::effects::Effect;
+use crate::enemies::powers::{CurlUp, Enrage, Split, SplitAction};
+
+/// Everything about an enemy that lives in `assets/enemies/data/<id>.json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct EnemyDefinition {
+    #[serde(skip)]
+    pub id: String,
+    pub name: String,
+    /// Folder under `assets/enemies/art`; without one it is drawn as a plain box.
+    #[serde(default)]
+    pub art: Option<String>,
+    pub max_health: i32,
+    /// Powers it has from the moment it enters the combat.
+    #[serde(default)]
+    pub powers: Vec<PowerSpec>,
+    pub moves: BTreeMap<String, MoveDefinition>,
+    pub ai: MoveSelection,
+    /// Definitions of the enemies its split moves create, read along with it.
+    #[serde(skip)]
+    pub split_targets: BTreeMap<String, Rc<EnemyDefinition>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MoveDefinition {
+    pub kind: IntentKind,
+    pub description: String,
+    pub actions: Vec<ActionSpec>,
+    /// Phase the enemy enters once it has picked this move.
+    #[serde(default)]
+    pub next_phase: Option<String>,
+}
+
+/// Who a move's status goes on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveTarget {
+    Player,
+    #[serde(rename = "self")]
+    Owner,
+}
+
+/// One step of a move, as written in the data file.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ActionSpec {
+    Damage {
+        amount: i32,
+        #[serde(default = "one_hit")]
+        hits: i32,
+    },
+    Block {
+        amount: i32,
+    },
+    Status {
+        status: StatusType,
+        stacks: i32,
+        target: MoveTarget,
+    },
+    /// Gives the enemy a power.
+    Power(PowerSpec),
+    /// Replaces the enemy with the listed ones, which keep its current HP.
+    Split {
+        into: Vec<String>,
+    },
+}
+
+/// A power an enemy starts with or gains from one of its moves.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "power", rename_all = "snake_case")]
+pub enum PowerSpec {
+    /// Gained during its own turn, so it only starts growing on the next one.
+    Ritual { amount: i32 },
+    CurlUp { amount: i32 },
+    Enrage { amount: i32 },
+    Split,
+}
+
+impl PowerSpec {
+    pub fn build(&self) -> Box<dyn Effect> {
+        match self {
+            PowerSpec::Ritual { amount } => Box::new(Ritual { amount: *amount, skip_first: true }),
+            PowerSpec::CurlUp { amount } => Box::new(CurlUp::new(*amount)),
+            PowerSpec::Enrage { amount } => Box::new(Enrage { amount: *amount }),
+            PowerSpec::Split => Box::new(Split::default()),
+        }
+    }
+}
+
+fn one_hit() -> i32 {
+    1
+}
+
+impl ActionSpec {
+    fn build(&self, owner: &EnemyDefinition) -> Vec<Box<dyn Action>> {
+        match self {
+            ActionSpec::Damage { amount, hits } => {
+                (0..*hits).map(|_| Box::new(DamageEffect { amount: *amount }) as Box<dyn Action>).collect()
+            }
+            ActionSpec::Block { amount } => vec![Box::new(BlockEffect { amount: *amount })],
+            ActionSpec::Status { status, stacks, target: MoveTarget::Player } => {
+                vec![Box::new(ApplyStatusAction { status_type: status.clone(), stacks: *stacks })]
+            }
+            ActionSpec::Status { status, stacks, target: MoveTarget::Owner } => {
+                vec![Box::new(GainStatusAction { status_type: status.clone(), stacks: *stacks })]
+            }
+            ActionSpec::Power(power) => vec![Box::new(ApplyEffect { effect: power.build() })],
+            ActionSpec::Split { into } => {
+                let into = into.iter().map(|id| Rc::clone(&owner.split_targets[id])).collect();
+                vec![Box::new(SplitAction { into })]
+            }
+        }
+    }
+}
+
+/// How the next move is picked. The opening moves come first, in order;
+/// after that the first conditional move whose condition holds wins, and
+/// otherwise the pattern decides.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MoveSelection {
+    /// Phase at the start of combat, for enemies whose behaviour changes, e.g. asleep and awake.
+    #[serde(default)]
+    pub initial_phase: Option<String>,
+    #[serde(default)]
+    pub opening: Vec<String>,
+    #[serde(default)]
+    pub conditional: Vec<ConditionalMove>,
+    pub pattern: MovePattern,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MovePattern {
+    /// The moves in order, starting over after the last one.
+    Cycle { moves: Vec<String> },
+    /// A random move by weight, skipping any that would go over its `max_in_a_row`.
+    Weighted { moves: Vec<WeightedMove> },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct WeightedMove {
+    #[serde(rename = "move")]
+    pub name: String,
+    pub weight: u32,
+    #[serde(default)]
+    pub max_in_a_row: Option<usize>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConditionalMove {
+    pub when: MoveCondition,
+    #[serde(rename = "move")]
+    pub name: String,
+    /// Only ever used once per combat, e.g. a split.
+    #[serde(default)]
+    pub once: bool,
+}
+
+/// Something the enemy checks against its own AI state and its view of the combat.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveCondition {
+    HealthBelowPercent(i32),
+    /// Another living enemy is below this share of its max HP.
+    AllyHealthBelowPercent(i32),
+    PlayerBlockAtLeast(i32),
+    /// Lost HP since it last picked a move.
+    Damaged,
+    Phase(String),
+    /// Has picked at least this many moves in its current phase.
+    TurnsInPhaseAtLeast(usize),
+    LastMove(String),
+    All(Vec<MoveCondition>),
+    Any(Vec<MoveCondition>),
+}
+
+impl EnemyDefinition {
+    /// Also reads the definitions of every enemy it splits into, so a split
+    /// cannot fail once the combat is under way.
+    pub fn from_json(id: &str, json: &str) -> Result<Self, String> {
+        Self::parse(id, json, &mut Vec::new())
+    }
+    
+    /// Reads `assets/enemies/data/<id>.json`.
+    pub fn load(id: &str) -> Result<Self, String> {
+        Self::read(id, &mut Vec::new())
+    }
+    
+    fn read(id: &str, loading: &mut Vec<String>) -> Result<Self, String> {
+        let path = format!("assets/enemies/data/{}.json", id);
+        let json = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path, e))?;
+        Self::parse(id, &json, loading)
+    }
+    
+    /// `loading` holds the enemies whose split targets are being read, to catch
+    /// enemies that split back into themselves.
+    fn parse(id: &str, json: &str, loading: &mut Vec<String>) -> Result<Self, String> {
+        let mut definition: EnemyDefinition = serde_json::from_str(json)
+            .map_err(|e| format!("Invalid enemy definition {}: {}", id, e))?;
+        definition.id = id.to_string();
+        definition.validate()?;
+        
+        loading.push(id.to_string());
+        let split_into: Vec<String> = definition.moves
+            .values()
+            .flat_map(|definition| &definition.actions)
+            .filter_map(|action| match action {
+                ActionSpec::Split { into } => Some(into.clone()),
+                _ => None,
+            })
+            .flatten()
+            .collect();
+        for target in split_into {
+            if loading.contains(&target) {
+                return Err(format!("Enemy {} splits back into {}", id, target));
+            }
+            if let Entry::Vacant(entry) = definition.split_targets.entry(target) {
+                let target_definition = Self::read(entry.key(), loading)
+                    .map_err(|e| format!("Enemy {} splits into {}: {}", id, entry.key(), e))?;
+                entry.insert(Rc::new(target_definition));
+            }
+        }
+        loading.pop();
+        Ok(definition)
+    }
+    
+    /// Every move the AI can pick has to exist, and so does the move a split power switches to.
+    fn validate(&self) -> Result<(), String> {
+        let pattern_moves: Vec<&String> = match &self.ai.pattern {
+            MovePattern::Cycle { moves } => moves.iter().collect(),
+            MovePattern::Weighted { moves } => moves.iter().map(|m| &m.name).collect(),
+        };
+        if pattern_moves.is_empty() {
+            return Err(format!("Enemy {} has no moves in its pattern", self.id));
+        }
+        
+        let referenced = self.ai.opening.iter()
+            .chain(self.ai.conditional.iter().map(|m| &m.name))
+            .chain(pattern_moves);
+        for name in referenced {
+            if !self.moves.contains_key(name) {
+                return Err(format!("Enemy {} uses unknown move {}", self.id, name));
+            }
+        }
+        
+        let splits = self.powers.iter().any(|power| matches!(power, PowerSpec::Split));
+        if splits && !self.moves.contains_key(Split::MOVE) {
+            return Err(format!("Enemy {} can split but has no {} move", self.id, Split::MOVE));
+        }
+        Ok(())
+    }
+    
+    /// Builds the intent for one of this enemy's moves, or for the built-in stunned move.
+    pub fn intent(&self, name: &str) -> Option<Intent> {
+        let Some(definition) = self.moves.get(name) else {
+            return (name == Intent::STUNNED).then(Intent::stunned);
+        };
+        let actions = definition.actions.iter().flat_map(|action| action.build(self)).collect();
+        Some(
+            Intent::new(actions, definition.description.clone())
+                .with_kind(definition.kind)
+                .with_move(name),
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use crate::core::damage::DamageKind;
+use crate::core::effects::{Effect, EffectUIState};
+use crate::core::game_state::{GameState, GameEvent, EntityId};
+use serde::{Serialize, Deserialize};
+
+/// The first time its owner takes attack damage, it curls up and gains block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CurlUp {
+    pub amount: i32,
+    #[serde(default)]
+    used: bool,
+}
+
+impl CurlUp {
+    pub fn new(amount: i32) -> Self {
+        CurlUp { amount, used: false }
+    }
+}
+
+impl Effect for CurlUp {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        if let GameEvent::DamageDealt { target, amount, kind: DamageKind::Attack, .. } = event
+            && *target == owner && *amount > 0 && !self.used
+        {
+            self.used = true;
+            game_state.gain_block(owner, self.amount);
+        }
+    }
+    
+    fn should_remove(&self) -> bool {
+        self.used
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState {
+            name: "Curl Up".to_string(),
+            description: format!("On receiving attack damage, curls up and gains {} Block.", self.amount),
+            counters: vec![],
+        }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "curl_up"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("CurlUp is always serializable")
+    }
+}
@@ -0,0 +1,41 @@
+use crate::core::card::CardType;
+use crate::core::effects::{Effect, EffectUIState};
+use crate::core::game_state::{GameState, GameEvent, EntityId};
+use crate::core::base_state::StatusType;
+use serde::{Serialize, Deserialize};
+
+/// Its owner gains Strength whenever someone else plays a Skill.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Enrage {
+    pub amount: i32,
+}
+
+impl Effect for Enrage {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        if let GameEvent::CardPlayed { source, card_type: CardType::Skill, .. } = event
+            && *source != owner
+        {
+            game_state.add_status(owner, StatusType::STRENGTH, self.amount);
+        }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState {
+            name: "Enrage".to_string(),
+            description: format!("Whenever a Skill is played, gains {} Strength.", self.amount),
+            counters: vec![],
+        }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "enrage"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Enrage is always serializable")
+    }
+}
@@ -0,0 +1,7 @@
+pub mod curl_up;
+pub mod enrage;
+pub mod split;
+
+pub use curl_up::CurlUp;
+pub use enrage::Enrage;
+pub use split::{Split, SplitAction};
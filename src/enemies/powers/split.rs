@@ -0,0 +1,88 @@
+use crate::core::action::Action;
+use crate::core::effects::{Effect, EffectUIState};
+use crate::core::enemy::Enemy;
+use crate::core::game_state::{GameState, GameEvent, EntityId};
+use crate::enemies::{DataEnemy, EnemyDefinition};
+use std::rc::Rc;
+use serde::{Serialize, Deserialize};
+
+/// Once its owner drops to half HP, its next move becomes its "split" move.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Split {
+    #[serde(default)]
+    used: bool,
+}
+
+impl Split {
+    pub const MOVE: &'static str = "split";
+}
+
+impl Effect for Split {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        let GameEvent::HpLost { entity, .. } = event else {
+            return;
+        };
+        let EntityId::Enemy(enemy_id) = owner else {
+            return;
+        };
+        if *entity != owner || self.used {
+            return;
+        }
+        
+        let intent = game_state.enemy(enemy_id)
+            .filter(|enemy| enemy.is_alive() && enemy.get_current_health() * 2 <= enemy.get_max_health())
+            .and_then(|enemy| enemy.move_intent(Self::MOVE));
+        if let Some(intent) = intent {
+            self.used = true;
+            game_state.set_enemy_intent(enemy_id, intent);
+        }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState {
+            name: "Split".to_string(),
+            description: "When its HP is at 50% or below, splits into smaller enemies.".to_string(),
+            counters: vec![],
+        }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "split"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("Split is always serializable")
+    }
+}
+
+/// Replaces the enemy performing it with the listed enemies, each starting
+/// with the splitter's current HP.
+#[derive(Debug, Clone)]
+pub struct SplitAction {
+    pub into: Vec<Rc<EnemyDefinition>>,
+}
+
+impl Action for SplitAction {
+    fn resolve(&self, game_state: &mut GameState, source: EntityId, _targets: &[EntityId], _energy_spent: Option<i32>) {
+        let EntityId::Enemy(enemy_id) = source else {
+            return;
+        };
+        let into = self.into
+            .iter()
+            .map(|definition| Box::new(DataEnemy::from_shared(Rc::clone(definition))) as Box<dyn Enemy>)
+            .collect();
+        game_state.split_enemy(enemy_id, into);
+    }
+    
+    fn description(&self) -> String {
+        "Split".to_string()
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
@@ -0,0 +1,34 @@
+use std::fs;
+
+use crate::core::enemy::Enemy;
+use crate::core::registry::{Registry, load_effect};
+use crate::enemies::{DataEnemy, CurlUp, Enrage, Split};
+
+const ENEMY_DATA_DIR: &str = "assets/enemies/data";
+
+/// Ids of every enemy with a data file, sorted.
+pub fn data_enemy_ids() -> Vec<String> {
+    let entries = fs::read_dir(ENEMY_DATA_DIR).expect("Failed to read enemy data directory");
+    let mut ids: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| path.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+        .collect();
+    ids.sort();
+    ids
+}
+
+/// Registers a `DataEnemy` for every file in `assets/enemies/data`.
+pub fn register_enemies(registry: &mut Registry) {
+    for id in data_enemy_ids() {
+        let factory_id = id.clone();
+        registry.register_enemy(&id, move || Box::new(DataEnemy::new(&factory_id)) as Box<dyn Enemy>, DataEnemy::from_state);
+    }
+}
+
+pub fn register_powers(registry: &mut Registry) {
+    registry.register_effect("curl_up", load_effect::<CurlUp>);
+    registry.register_effect("enrage", load_effect::<Enrage>);
+    registry.register_effect("split", load_effect::<Split>);
+}
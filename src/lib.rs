@@ -1,4 +1,8 @@
 pub mod core;
 pub mod enemies;
+pub mod encounters;
 pub mod cards;
+pub mod save;
 pub mod ui;
+pub mod sim;
+pub mod ai;
@@ -13,10 +13,12 @@ use SlayTheSpire::ui::screens::{
 use SlayTheSpire::core::player::{Player, STSClass};
 use SlayTheSpire::core::base_state::State;
 use SlayTheSpire::core::card::Card;
-use SlayTheSpire::core::enemy::Enemy;
 use SlayTheSpire::core::game_state::GameState as CombatState;
-use SlayTheSpire::enemies::dragonling::Dragonling;
+use SlayTheSpire::core::rng::GameRng;
+use SlayTheSpire::core::registry::Registry;
+use SlayTheSpire::encounters::{ActEncounters, EncounterHistory, EncounterTier};
 use SlayTheSpire::cards;
+use SlayTheSpire::save::{self, Replay, RunSave, REPLAY_PATH, RUN_SAVE_PATH};
 
 enum GameScreen {
     Menu,
@@ -37,104 +39,204 @@ struct GameState {
     player: Player,
     deck: Vec<Card>,
     deck_before_combat: Vec<Card>,
+    
+    seed_override: Option<u64>,
+    rng: GameRng,
+    registry: Registry,
+    act_encounters: ActEncounters,
+    encounter_history: EncounterHistory,
 }
 
 impl GameState {
-    fn create_starting_deck() -> Vec<Card> {
-        vec![
-            cards::strike(1, false),
-            cards::strike(2, false),
-            cards::defend(3, false),
-            cards::defend(4, false),
-            cards::defend(5, false),
-            cards::inflame(6, false),
-            cards::haste(7, false),
-            cards::whirlwind(8, false),
-            cards::barricade(9, false),
-            cards::quick_strike(10, false),
-        ]
-    }
-    
-    fn new(ctx: &mut Context) -> GameResult<GameState> {
+    fn new(ctx: &mut Context, seed_override: Option<u64>, act_encounters: ActEncounters) -> GameResult<GameState> {
         let assets = Arc::new(Assets::new(ctx));
         let player = Player::new(STSClass::Ironclad, "Hero".to_string(), 100);
+        let mut menu_screen = MenuScreen::new();
+        menu_screen.set_can_continue(RunSave::exists(RUN_SAVE_PATH));
         
         Ok(GameState {
             assets,
             current_screen: GameScreen::Menu,
-            menu_screen: MenuScreen::new(),
+            menu_screen,
             map_screen: None,
             combat_screen: None,
             card_reward_screen: None,
             player,
-            deck: Self::create_starting_deck(),
+            deck: cards::starting_deck(),
             deck_before_combat: Vec::new(),
+            seed_override,
+            rng: GameRng::from_entropy(),
+            registry: save::default_registry(),
+            act_encounters,
+            encounter_history: EncounterHistory::default(),
         })
     }
     
+    /// Writes the run to disk. Pass the combat when the player is inside a
+    /// combat node so it can be resumed from the same point.
+    fn autosave(&self, combat: Option<&CombatState>) {
+        let Some(map) = &self.map_screen else {
+            return;
+        };
+        
+        let deck = if combat.is_some() { &self.deck_before_combat } else { &self.deck };
+        let mut run_save = RunSave::new(&self.player, deck, map.floor(), map.current_node_index(), &self.rng)
+            .with_encounters(&self.encounter_history);
+        if let Some(combat) = combat {
+            run_save = run_save.with_combat(combat.snapshot());
+        }
+        
+        if let Err(err) = run_save.write(RUN_SAVE_PATH) {
+            eprintln!("Warning: failed to autosave run: {}", err);
+        }
+    }
+    
+    fn end_run(&mut self) {
+        if let Err(err) = RunSave::delete(RUN_SAVE_PATH) {
+            eprintln!("Warning: failed to delete run save: {}", err);
+        }
+        self.menu_screen.set_can_continue(false);
+        
+        self.current_screen = GameScreen::Menu;
+        self.map_screen = None;
+        self.combat_screen = None;
+        self.card_reward_screen = None;
+    }
+    
+    fn continue_run(&mut self) {
+        let run_save = match RunSave::read(RUN_SAVE_PATH) {
+            Ok(run_save) => run_save,
+            Err(err) => {
+                eprintln!("Failed to load run save: {}", err);
+                self.menu_screen.set_can_continue(false);
+                return;
+            }
+        };
+        
+        self.player = run_save.player.clone();
+        self.deck = run_save.restore_deck(&self.registry);
+        self.rng = run_save.rng.clone();
+        self.encounter_history = run_save.encounters.clone();
+        self.map_screen = Some(MapScreen::resume(run_save.floor, run_save.current_node_index));
+        self.current_screen = GameScreen::Map;
+        println!("Continuing run with seed {}", self.rng.seed());
+        
+        if let Some(snapshot) = run_save.combat {
+            match CombatState::from_snapshot(snapshot, &self.registry) {
+                Ok(combat_state) => {
+                    self.deck_before_combat = self.deck.clone();
+                    self.combat_screen = Some(CombatScreen::resume_with_state(combat_state, &self.assets));
+                    self.current_screen = GameScreen::Combat;
+                }
+                Err(err) => {
+                    eprintln!("Failed to restore saved combat, returning to map: {}", err);
+                }
+            }
+        }
+    }
+    
     fn start_new_run(&mut self) {
         self.player = Player::new(STSClass::Ironclad, "Hero".to_string(), 100);
-        self.deck = Self::create_starting_deck();
+        self.deck = cards::starting_deck();
+        self.rng = match self.seed_override {
+            Some(seed) => GameRng::new(seed),
+            None => GameRng::from_entropy(),
+        };
+        println!("Starting run with seed {}", self.rng.seed());
+        self.encounter_history = EncounterHistory::default();
         
         self.map_screen = Some(MapScreen::new());
         self.current_screen = GameScreen::Map;
+        self.autosave(None);
+    }
+    
+    /// A node that fails to set up is reported and the player stays on the map.
+    fn enter_node(&mut self, node_type: NodeType) {
+        if let Err(err) = self.enter_combat(node_type) {
+            eprintln!("Failed to start combat, staying on the map: {}", err);
+            self.current_screen = GameScreen::Map;
+        }
     }
     
-    fn enter_combat(&mut self, node_type: NodeType) {
-        let enemies: Vec<Box<dyn Enemy>> = match node_type {
-            NodeType::NormalCombat => vec![
-                Box::new(Dragonling::new()),
-            ],
-            NodeType::EliteCombat => vec![
-                Box::new(Dragonling::new()),
-                Box::new(Dragonling::new()),
-            ],
-            NodeType::Boss => vec![
-                Box::new(Dragonling::new()),
-                Box::new(Dragonling::new()),
-                Box::new(Dragonling::new()),
-            ],
+    fn enter_combat(&mut self, node_type: NodeType) -> Result<(), String> {
+        let tier = match node_type {
+            NodeType::NormalCombat => self.act_encounters.hallway_tier(&self.encounter_history),
+            NodeType::EliteCombat => EncounterTier::Elite,
+            NodeType::Boss => EncounterTier::Boss,
             NodeType::RestSite => {
-                let max_hp = self.player.get_max_health();
-                let current_hp = self.player.get_current_health();
-                let heal_amount = (max_hp as f32 * 0.3) as i32;
-                let new_hp = (current_hp + heal_amount).min(max_hp);
-                self.player.set_health(new_hp);
+                let heal_amount = (self.player.get_max_health() as f32 * 0.3) as i32;
+                self.player.heal(heal_amount);
                 
                 if let Some(map) = &mut self.map_screen {
                     map.advance_floor();
                 }
                 self.current_screen = GameScreen::Map;
-                return;
+                self.autosave(None);
+                return Ok(());
             }
         };
         
+        let mut encounter_history = self.encounter_history.clone();
+        let mut rng = self.rng.clone();
+        let combat_state = self.start_encounter(tier, &mut encounter_history, &mut rng)?;
+        self.encounter_history = encounter_history;
+        self.rng = rng;
         self.deck_before_combat = self.deck.clone();
         
-        let combat_state = CombatState::new_with_deck(self.player.clone(), enemies, self.deck.clone());
-        self.combat_screen = Some(CombatScreen::new_with_state(combat_state, &self.assets));
+        let combat_screen = CombatScreen::new_with_state(combat_state, &self.assets);
+        self.autosave(Some(combat_screen.game_state()));
+        self.combat_screen = Some(combat_screen);
         self.current_screen = GameScreen::Combat;
+        Ok(())
+    }
+    
+    /// Sets up the next encounter of `tier`. The run only takes the updated
+    /// history and rng once the whole combat is ready.
+    fn start_encounter(&self, tier: EncounterTier, history: &mut EncounterHistory, rng: &mut GameRng) -> Result<CombatState, String> {
+        let encounter = self.act_encounters.next_encounter(tier, history, rng)?;
+        let enemies = encounter.create_enemies(&self.registry)
+            .map_err(|err| format!("Failed to set up encounter {}: {}", encounter.id, err))?;
+        
+        CombatState::new_with_rng(self.player.clone(), enemies, self.deck.clone(), rng.clone())
+            .with_enemy_slots(&encounter.slots())
+            .map_err(|err| format!("Failed to set up encounter {}: {}", encounter.id, err))
+    }
+    
+    /// Keeps the last combat on disk so it can be attached to bug reports.
+    fn write_combat_replay(&self) {
+        let Some(combat) = &self.combat_screen else {
+            return;
+        };
+        let Some(recording) = combat.game_state().recording() else {
+            return;
+        };
+        
+        if let Err(err) = Replay::from_recording(recording).write(REPLAY_PATH) {
+            eprintln!("Warning: failed to write combat replay: {}", err);
+        }
     }
     
     fn combat_victory(&mut self) {
+        self.write_combat_replay();
+        
         if let Some(combat) = &self.combat_screen {
             self.player = combat.get_player().clone();
+            self.rng = combat.get_rng().clone();
             
             self.player.clear_all_statuses();
             self.player.clear_all_modifiers();
+            self.player.set_temp_hp(0);
             
             self.deck = self.deck_before_combat.clone();
         }
         
-        self.card_reward_screen = Some(CardRewardScreen::new(self.assets.clone()));
+        self.card_reward_screen = Some(CardRewardScreen::new(self.assets.clone(), &mut self.rng));
         self.current_screen = GameScreen::CardReward;
     }
     
     fn combat_defeat(&mut self) {
-        self.current_screen = GameScreen::Menu;
-        self.map_screen = None;
-        self.combat_screen = None;
-        self.card_reward_screen = None;
+        self.write_combat_replay();
+        self.end_run();
     }
     
     fn finish_reward(&mut self, card: Option<Card>) {
@@ -149,16 +251,15 @@ impl GameState {
         };
         
         if defeated_boss {
-            self.current_screen = GameScreen::Menu;
-            self.map_screen = None;
-            self.combat_screen = None;
-            self.card_reward_screen = None;
+            self.end_run();
         } else {
             if let Some(map) = &mut self.map_screen {
                 map.advance_floor();
             }
             self.current_screen = GameScreen::Map;
             self.card_reward_screen = None;
+            self.combat_screen = None;
+            self.autosave(None);
         }
     }
 }
@@ -169,6 +270,9 @@ impl EventHandler for GameState {
             GameScreen::Menu => {
                 let action = self.menu_screen.update(ctx)?;
                 match action {
+                    MenuAction::Continue => {
+                        self.continue_run();
+                    }
                     MenuAction::StartRun => {
                         self.start_new_run();
                     }
@@ -183,7 +287,7 @@ impl EventHandler for GameState {
                     let action = map.update(ctx)?;
                     match action {
                         MapAction::EnterNode(node_type) => {
-                            self.enter_combat(node_type);
+                            self.enter_node(node_type);
                         }
                         MapAction::None => {}
                     }
@@ -221,7 +325,7 @@ impl EventHandler for GameState {
         
         Ok(())
     }
-
+    
     fn draw(&mut self, ctx: &mut Context) -> GameResult {
         let mut canvas = graphics::Canvas::from_frame(ctx, Color::from_rgb(20, 20, 30));
         
@@ -249,7 +353,7 @@ impl EventHandler for GameState {
         canvas.finish(ctx)?;
         Ok(())
     }
-
+    
     fn mouse_button_down_event(
         &mut self,
         ctx: &mut Context,
@@ -261,6 +365,9 @@ impl EventHandler for GameState {
             GameScreen::Menu => {
                 let action = self.menu_screen.mouse_button_down(ctx, button, x, y)?;
                 match action {
+                    MenuAction::Continue => {
+                        self.continue_run();
+                    }
                     MenuAction::StartRun => {
                         self.start_new_run();
                     }
@@ -275,7 +382,7 @@ impl EventHandler for GameState {
                     let action = map.mouse_button_down(ctx, button, x, y)?;
                     match action {
                         MapAction::EnterNode(node_type) => {
-                            self.enter_combat(node_type);
+                            self.enter_node(node_type);
                         }
                         MapAction::None => {}
                     }
@@ -313,7 +420,7 @@ impl EventHandler for GameState {
         
         Ok(())
     }
-
+    
     fn mouse_motion_event(
         &mut self,
         ctx: &mut Context,
@@ -340,14 +447,30 @@ impl EventHandler for GameState {
     }
 }
 
+fn parse_seed_arg() -> Option<u64> {
+    let args: Vec<String> = std::env::args().collect();
+    args.iter()
+        .position(|arg| arg == "--seed")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|seed| seed.parse().ok())
+}
+
 fn main() -> GameResult {
+    let act_encounters = match ActEncounters::load(1) {
+        Ok(act_encounters) => act_encounters,
+        Err(err) => {
+            eprintln!("Cannot start the game, the Act 1 encounters failed to load: {}", err);
+            std::process::exit(1);
+        }
+    };
+    
     let (mut ctx, event_loop) = ContextBuilder::new("slay_the_spire", "Ivaylo")
         .window_setup(ggez::conf::WindowSetup::default().title("Slay the Spire"))
         .window_mode(ggez::conf::WindowMode::default().dimensions(1400.0, 800.0))
         .add_resource_path("assets")
         .build()?;
-
-    let game_state = GameState::new(&mut ctx)?;
+    
+    let game_state = GameState::new(&mut ctx, parse_seed_arg(), act_encounters)?;
     
     event::run(ctx, event_loop, game_state)
 }
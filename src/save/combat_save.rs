@@ -0,0 +1,57 @@
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+
+use crate::core::game_state::GameState;
+use crate::core::registry::Registry;
+use crate::core::snapshot::GameStateSnapshot;
+
+/// Bump whenever the layout of `GameStateSnapshot` changes incompatibly.
+pub const COMBAT_SAVE_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize)]
+pub struct CombatSave {
+    pub version: u32,
+    pub state: GameStateSnapshot,
+}
+
+impl CombatSave {
+    pub fn new(game_state: &GameState) -> Self {
+        CombatSave {
+            version: COMBAT_SAVE_VERSION,
+            state: game_state.snapshot(),
+        }
+    }
+    
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+    
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let save: CombatSave = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if save.version != COMBAT_SAVE_VERSION {
+            return Err(format!(
+                "Unsupported combat save version {} (expected {})",
+                save.version, COMBAT_SAVE_VERSION
+            ));
+        }
+        Ok(save)
+    }
+    
+    pub fn restore(self, registry: &Registry) -> Result<GameState, String> {
+        GameState::from_snapshot(self.state, registry)
+    }
+}
+
+pub fn save_combat(game_state: &GameState, path: impl AsRef<Path>) -> Result<(), String> {
+    let json = CombatSave::new(game_state).to_json()?;
+    if let Some(parent) = path.as_ref().parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    fs::write(path, json).map_err(|e| e.to_string())
+}
+
+pub fn load_combat(path: impl AsRef<Path>, registry: &Registry) -> Result<GameState, String> {
+    let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    CombatSave::from_json(&json)?.restore(registry)
+}
@@ -0,0 +1,20 @@
+pub mod combat_save;
+pub mod run_save;
+pub mod replay;
+
+pub use combat_save::{CombatSave, COMBAT_SAVE_VERSION, save_combat, load_combat};
+pub use run_save::{RunSave, RUN_SAVE_VERSION, RUN_SAVE_PATH};
+pub use replay::{Replay, REPLAY_VERSION, REPLAY_PATH};
+
+use crate::core::registry::Registry;
+use crate::{cards, enemies};
+
+/// Registry with every card, effect and enemy shipped with the game.
+pub fn default_registry() -> Registry {
+    let mut registry = Registry::new();
+    cards::register_cards(&mut registry);
+    cards::register_effects(&mut registry);
+    enemies::register_enemies(&mut registry);
+    enemies::register_powers(&mut registry);
+    registry
+}
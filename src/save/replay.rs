@@ -0,0 +1,106 @@
+use serde::{Serialize, Deserialize};
+use std::fs;
+use std::path::Path;
+
+use crate::core::combat_log::{CombatRecording, LogEntry, LogRecord};
+use crate::core::game_state::GameState;
+use crate::core::player_action::PlayerAction;
+use crate::core::registry::Registry;
+use crate::core::snapshot::GameStateSnapshot;
+
+pub const REPLAY_VERSION: u32 = 1;
+pub const REPLAY_PATH: &str = "saves/last_combat.replay.json";
+
+/// A recorded combat: the state it started from, including the rng seed and
+/// stream positions, plus the full log. Feeding the logged inputs back into
+/// the starting state reproduces the same log.
+#[derive(Serialize, Deserialize)]
+pub struct Replay {
+    pub version: u32,
+    pub seed: u64,
+    pub initial: GameStateSnapshot,
+    pub log: Vec<LogEntry>,
+}
+
+impl Replay {
+    pub fn from_recording(recording: &CombatRecording) -> Self {
+        Replay {
+            version: REPLAY_VERSION,
+            seed: recording.initial.rng.seed(),
+            initial: recording.initial.clone(),
+            log: recording.log.entries().to_vec(),
+        }
+    }
+    
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+    
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let replay: Replay = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        if replay.version != REPLAY_VERSION {
+            return Err(format!(
+                "Unsupported replay version {} (expected {})",
+                replay.version, REPLAY_VERSION
+            ));
+        }
+        Ok(replay)
+    }
+    
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, self.to_json()?).map_err(|e| e.to_string())
+    }
+    
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, String> {
+        let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::from_json(&json)
+    }
+    
+    pub fn inputs(&self) -> Vec<PlayerAction> {
+        self.log
+            .iter()
+            .filter_map(|entry| match &entry.record {
+                LogRecord::Input(action) => Some(*action),
+                LogRecord::Event(_) => None,
+            })
+            .collect()
+    }
+    
+    /// Restores the starting state and applies the recorded inputs in order,
+    /// recording a fresh log along the way.
+    pub fn reexecute(&self, registry: &Registry) -> Result<GameState, String> {
+        let mut game_state = GameState::from_snapshot(self.initial.clone(), registry)?;
+        game_state.start_recording();
+        
+        for (i, action) in self.inputs().into_iter().enumerate() {
+            game_state.apply(action)
+                .map_err(|e| format!("Input {} ({:?}) was rejected: {}", i, action, e))?;
+        }
+        
+        Ok(game_state)
+    }
+    
+    /// Re-executes the replay and reports the first log entry that differs.
+    pub fn verify(&self, registry: &Registry) -> Result<(), String> {
+        let game_state = self.reexecute(registry)?;
+        let replayed = game_state.recording().map(|r| r.log.entries()).unwrap_or(&[]);
+        
+        for (i, (expected, actual)) in self.log.iter().zip(replayed.iter()).enumerate() {
+            if expected != actual {
+                return Err(format!("Replay diverged at entry {}: expected {:?}, got {:?}", i, expected, actual));
+            }
+        }
+        
+        if self.log.len() != replayed.len() {
+            return Err(format!(
+                "Replay produced {} entries, expected {}",
+                replayed.len(), self.log.len()
+            ));
+        }
+        
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use serde::{Serialize, Deserialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+use crate::core::card::Card;
+use crate::core::player::Player;
+use crate::core::registry::Registry;
+use crate::core::rng::GameRng;
+use crate::core::snapshot::{CardSnapshot, GameStateSnapshot};
+use crate::encounters::EncounterHistory;
+
+/// Bump when `RunSave` changes and add the matching step to `MIGRATIONS`.
+pub const RUN_SAVE_VERSION: u32 = 1;
+pub const RUN_SAVE_PATH: &str = "saves/run.json";
+
+/// Upgrades a raw save from version `i + 1` to `i + 2`, where `i` is the
+/// index in `MIGRATIONS`.
+type Migration = fn(&mut Value) -> Result<(), String>;
+
+const MIGRATIONS: &[Migration] = &[];
+
+/// Everything about a run that must survive quitting the game. `combat` is
+/// only present while the player is inside a combat node.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RunSave {
+    pub version: u32,
+    pub player: Player,
+    pub deck: Vec<CardSnapshot>,
+    pub floor: usize,
+    pub current_node_index: usize,
+    pub rng: GameRng,
+    #[serde(default)]
+    pub encounters: EncounterHistory,
+    pub combat: Option<GameStateSnapshot>,
+}
+
+impl RunSave {
+    pub fn new(player: &Player, deck: &[Card], floor: usize, current_node_index: usize, rng: &GameRng) -> Self {
+        RunSave {
+            version: RUN_SAVE_VERSION,
+            player: player.clone(),
+            deck: deck.iter().map(CardSnapshot::from_card).collect(),
+            floor,
+            current_node_index,
+            rng: rng.clone(),
+            encounters: EncounterHistory::default(),
+            combat: None,
+        }
+    }
+    
+    pub fn with_encounters(mut self, encounters: &EncounterHistory) -> Self {
+        self.encounters = encounters.clone();
+        self
+    }
+    
+    pub fn with_combat(mut self, combat: GameStateSnapshot) -> Self {
+        self.combat = Some(combat);
+        self
+    }
+    
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+    
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let mut value: Value = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        migrate(&mut value)?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+    
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        if let Some(parent) = path.as_ref().parent() {
+            fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+        }
+        fs::write(path, self.to_json()?).map_err(|e| e.to_string())
+    }
+    
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, String> {
+        let json = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        Self::from_json(&json)
+    }
+    
+    pub fn exists(path: impl AsRef<Path>) -> bool {
+        path.as_ref().is_file()
+    }
+    
+    pub fn delete(path: impl AsRef<Path>) -> Result<(), String> {
+        match fs::remove_file(path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+    
+    /// Rebuilds the deck from current card data. Cards whose id no longer
+    /// exists are dropped so that removing a card never bricks a save.
+    pub fn restore_deck(&self, registry: &Registry) -> Vec<Card> {
+        self.deck
+            .iter()
+            .filter_map(|card| match card.restore(registry) {
+                Ok(card) => Some(card),
+                Err(err) => {
+                    eprintln!("Warning: dropping card from save: {}", err);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Runs every migration between the save's version and `RUN_SAVE_VERSION`.
+pub fn migrate(value: &mut Value) -> Result<(), String> {
+    let version = value
+        .get("version")
+        .and_then(Value::as_u64)
+        .ok_or("Run save has no version")? as u32;
+    
+    if version == 0 || version > RUN_SAVE_VERSION {
+        return Err(format!("Unsupported run save version {} (latest is {})", version, RUN_SAVE_VERSION));
+    }
+    
+    for (index, migration) in MIGRATIONS.iter().enumerate().skip(version as usize - 1) {
+        migration(value)?;
+        value["version"] = Value::from(index as u32 + 2);
+    }
+    
+    Ok(())
+}
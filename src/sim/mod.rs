@@ -0,0 +1,5 @@
+pub mod policy;
+pub mod runner;
+
+pub use policy::{PlayerPolicy, RandomPolicy, GreedyPolicy, policy_by_name, POLICY_NAMES};
+pub use runner::{SimulationConfig, CombatResult, SimulationReport, run_combat, run_simulation, parse_deck_list};
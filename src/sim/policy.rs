@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha8Rng;
+
+use crate::core::card::CardType;
+use crate::core::game_state::{EntityId, GameState};
+use crate::core::player_action::PlayerAction;
+use crate::ai::mcts::{MctsConfig, MctsPolicy};
+
+/// Chooses the player's moves in a headless combat.
+pub trait PlayerPolicy {
+    fn name(&self) -> &str;
+    fn decide(&mut self, state: &GameState) -> PlayerAction;
+}
+
+pub const POLICY_NAMES: &[&str] = &["random", "greedy", "mcts"];
+
+/// Iterations per decision when the simulator runs the tree search policy.
+const SIMULATOR_MCTS_ITERATIONS: usize = 300;
+
+pub fn policy_by_name(name: &str, seed: u64) -> Option<Box<dyn PlayerPolicy>> {
+    match name {
+        "random" => Some(Box::new(RandomPolicy::new(seed))),
+        "greedy" => Some(Box::new(GreedyPolicy)),
+        "mcts" => Some(Box::new(MctsPolicy::new(MctsConfig {
+            iterations: SIMULATOR_MCTS_ITERATIONS,
+            time_budget: Duration::from_secs(10),
+            seed,
+            ..MctsConfig::default()
+        }))),
+        _ => None,
+    }
+}
+
+/// Picks uniformly among the legal actions, ending the turn included.
+pub struct RandomPolicy {
+    rng: ChaCha8Rng,
+}
+
+impl RandomPolicy {
+    pub fn new(seed: u64) -> Self {
+        RandomPolicy { rng: ChaCha8Rng::seed_from_u64(seed) }
+    }
+}
+
+impl PlayerPolicy for RandomPolicy {
+    fn name(&self) -> &str {
+        "random"
+    }
+    
+    fn decide(&mut self, state: &GameState) -> PlayerAction {
+        let actions = state.legal_actions();
+        actions.choose(&mut self.rng).copied().unwrap_or(PlayerAction::EndTurn)
+    }
+}
+
+/// Plays powers first, then the most expensive attack at the weakest enemy,
+/// then skills, and ends the turn once nothing affordable is left.
+pub struct GreedyPolicy;
+
+impl GreedyPolicy {
+    fn priority(card_type: &CardType) -> i32 {
+        match card_type {
+            CardType::Power => 2,
+            CardType::Attack => 1,
+            CardType::Skill => 0,
+        }
+    }
+}
+
+impl PlayerPolicy for GreedyPolicy {
+    fn name(&self) -> &str {
+        "greedy"
+    }
+    
+    fn decide(&mut self, state: &GameState) -> PlayerAction {
+        let enemy_health = |target: &Option<EntityId>| match target {
+            Some(EntityId::Enemy(id)) => state.enemy(*id).map_or(i32::MAX, |e| e.get_current_health()),
+            _ => 0,
+        };
+        
+        state.legal_actions()
+            .into_iter()
+            .filter_map(|action| match action {
+                PlayerAction::PlayCard { hand_index, target } => Some((hand_index, target)),
+                _ => None,
+            })
+            .max_by_key(|(hand_index, target)| {
+                let card = &state.hand()[*hand_index];
+                let cost = card.get_current_cost().ok().flatten().unwrap_or(0);
+                (Self::priority(card.card_type()), cost, -enemy_health(target))
+            })
+            .map_or(PlayerAction::EndTurn, |(hand_index, target)| PlayerAction::PlayCard { hand_index, target })
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::HashMap;
+
+use crate::core::base_state::State;
+use crate::core::card::Card;
+use crate::core::game_state::{GameState, MAX_ENEMIES};
+use crate::core::player::{Player, STSClass};
+use crate::core::registry::Registry;
+use crate::core::rng::GameRng;
+use crate::core::player_action::PlayerAction;
+use crate::sim::policy::PlayerPolicy;
+
+/// Safety valve against policies that never end their turn.
+const MAX_DECISIONS_PER_TURN: usize = 100;
+
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    /// Card ids, with a trailing `+` for upgraded copies (e.g. `strike+`).
+    pub deck: Vec<String>,
+    /// Enemy ids fought together in one combat.
+    pub encounter: Vec<String>,
+    pub seed: u64,
+    pub combats: usize,
+    pub player_health: i32,
+    pub max_turns: usize,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        SimulationConfig {
+            deck: Vec::new(),
+            encounter: vec!["dragonling".to_string()],
+            seed: 0,
+            combats: 100,
+            player_health: 100,
+            max_turns: 50,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CombatResult {
+    pub won: bool,
+    pub hp_lost: i32,
+    pub turns: usize,
+    pub damage_by_card: HashMap<String, i32>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SimulationReport {
+    pub combats: usize,
+    pub wins: usize,
+    pub total_hp_lost: i32,
+    pub total_turns: usize,
+    pub damage_by_card: HashMap<String, i32>,
+}
+
+impl SimulationReport {
+    pub fn record(&mut self, result: &CombatResult) {
+        self.combats += 1;
+        if result.won {
+            self.wins += 1;
+        }
+        self.total_hp_lost += result.hp_lost;
+        self.total_turns += result.turns;
+        for (card_id, damage) in &result.damage_by_card {
+            *self.damage_by_card.entry(card_id.clone()).or_insert(0) += damage;
+        }
+    }
+    
+    pub fn win_rate(&self) -> f64 {
+        if self.combats == 0 { 0.0 } else { self.wins as f64 / self.combats as f64 }
+    }
+    
+    pub fn average_hp_lost(&self) -> f64 {
+        if self.combats == 0 { 0.0 } else { self.total_hp_lost as f64 / self.combats as f64 }
+    }
+    
+    pub fn average_turns(&self) -> f64 {
+        if self.combats == 0 { 0.0 } else { self.total_turns as f64 / self.combats as f64 }
+    }
+    
+    /// Per-card damage totals, highest first.
+    pub fn damage_ranking(&self) -> Vec<(&str, i32)> {
+        let mut ranking: Vec<(&str, i32)> = self.damage_by_card
+            .iter()
+            .map(|(id, damage)| (id.as_str(), *damage))
+            .collect();
+        ranking.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        ranking
+    }
+}
+
+/// Parses a comma separated deck list such as `strike,strike,defend+`.
+pub fn parse_deck_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(|entry| entry.trim().to_string())
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+fn build_deck(registry: &Registry, deck: &[String]) -> Result<Vec<Card>, String> {
+    deck.iter()
+        .enumerate()
+        .map(|(index, entry)| {
+            let (id, upgraded) = match entry.strip_suffix('+') {
+                Some(id) => (id, true),
+                None => (entry.as_str(), false),
+            };
+            registry.create_card(id, index as u32 + 1, upgraded)
+        })
+        .collect()
+}
+
+fn total_enemy_health(state: &GameState) -> i32 {
+    state.enemies()
+        .iter()
+        .chain(state.graveyard().iter())
+        .map(|(_, enemy)| enemy.get_current_health().max(0))
+        .sum()
+}
+
+/// Plays out a single combat with the given seed and returns how it went.
+pub fn run_combat(
+    registry: &Registry,
+    config: &SimulationConfig,
+    seed: u64,
+    policy: &mut dyn PlayerPolicy,
+) -> Result<CombatResult, String> {
+    if config.encounter.len() > MAX_ENEMIES {
+        return Err(format!("An encounter has at most {} enemies", MAX_ENEMIES));
+    }
+    let deck = build_deck(registry, &config.deck)?;
+    let enemies = config.encounter
+        .iter()
+        .map(|id| registry.create_enemy(id))
+        .collect::<Result<Vec<_>, String>>()?;
+    let player = Player::new(STSClass::Ironclad, "Simulated".to_string(), config.player_health);
+    
+    let mut state = GameState::new_with_rng(player, enemies, deck, GameRng::new(seed));
+    let mut result = CombatResult::default();
+    
+    state.start_combat();
+    result.turns = 1;
+    let mut decisions = 0;
+    
+    while !state.is_combat_over() && result.turns <= config.max_turns {
+        let mut action = if decisions < MAX_DECISIONS_PER_TURN {
+            policy.decide(&state)
+        } else {
+            PlayerAction::EndTurn
+        };
+        if state.validate_action(&action).is_err() {
+            action = PlayerAction::EndTurn;
+        }
+        
+        let played_card = match action {
+            PlayerAction::PlayCard { hand_index, .. } => Some(state.hand()[hand_index].id().to_string()),
+            _ => None,
+        };
+        let health_before = total_enemy_health(&state);
+        
+        state.apply(action)?;
+        decisions += 1;
+        
+        if let Some(card_id) = played_card {
+            let damage = (health_before - total_enemy_health(&state)).max(0);
+            *result.damage_by_card.entry(card_id).or_insert(0) += damage;
+        }
+        
+        if action == PlayerAction::EndTurn && !state.is_combat_over() {
+            result.turns += 1;
+            decisions = 0;
+        }
+    }
+    
+    result.turns = result.turns.min(config.max_turns);
+    result.won = state.are_all_enemies_dead() && !state.is_player_dead();
+    result.hp_lost = config.player_health - state.player().get_current_health().max(0);
+    
+    Ok(result)
+}
+
+/// Runs `config.combats` combats, seeding combat `i` with `config.seed + i`.
+pub fn run_simulation<F>(
+    registry: &Registry,
+    config: &SimulationConfig,
+    mut make_policy: F,
+) -> Result<SimulationReport, String>
+where
+    F: FnMut(u64) -> Box<dyn PlayerPolicy>,
+{
+    let mut report = SimulationReport::default();
+    
+    for i in 0..config.combats {
+        let seed = config.seed.wrapping_add(i as u64);
+        let mut policy = make_policy(seed);
+        let result = run_combat(registry, config, seed, policy.as_mut())?;
+        report.record(&result);
+    }
+    
+    Ok(report)
+}
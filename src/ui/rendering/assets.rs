@@ -2,6 +2,8 @@ use ggez::graphics::Image;
 use ggez::Context;
 use std::collections::HashMap;
 
+use crate::enemies::{data_enemy_ids, EnemyDefinition};
+
 pub struct Assets {
     images: HashMap<String, Image>,
     animations: HashMap<String, Vec<Image>>,
@@ -27,7 +29,13 @@ impl Assets {
         self.load_card_art(ctx, "whirlwind");
         self.load_card_art(ctx, "quick_strike");
         
-        self.load_animation(ctx, "dragonling_idle", "enemies/art/dragonling", 4);
+        for id in data_enemy_ids() {
+            match EnemyDefinition::load(&id) {
+                Ok(EnemyDefinition { art: Some(art), .. }) => self.load_enemy_art(ctx, &art),
+                Ok(_) => {}
+                Err(e) => eprintln!("Warning: {}", e),
+            }
+        }
         self.load_animation(ctx, "player_idle", "player/art", 10);
     }
     
@@ -40,6 +48,17 @@ impl Assets {
         }
     }
     
+    /// Loads `enemies/art/<art>/idle1.png`, `idle2.png`, ... as `<art>_idle`.
+    /// Enemies without art are drawn as plain boxes.
+    fn load_enemy_art(&mut self, ctx: &mut Context, art: &str) {
+        let frames: Vec<Image> = (1..)
+            .map_while(|i| Image::from_path(ctx, format!("/enemies/art/{}/idle{}.png", art, i)).ok())
+            .collect();
+        if !frames.is_empty() {
+            self.animations.insert(format!("{}_idle", art), frames);
+        }
+    }
+    
     fn load_animation(&mut self, ctx: &mut Context, anim_name: &str, base_path: &str, frame_count: usize) {
         let mut frames = Vec::new();
         for i in 1..=frame_count {
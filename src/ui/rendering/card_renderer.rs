@@ -6,7 +6,7 @@ use crate::core::card::{Card, Cost, CardType};
 use super::theme::Theme;
 use super::assets::Assets;
 
-pub struct CardRenderConfig {
+pub struct CardRenderConfig<'a> {
     pub x: f32,
     pub y: f32,
     pub width: f32,
@@ -15,10 +15,11 @@ pub struct CardRenderConfig {
     pub hovering: bool,
     pub scale: f32,
     pub theme: Theme,
-    pub player_strength: i32,
+    /// Turns a card's printed damage into what it would really deal.
+    pub damage_preview: Option<&'a dyn Fn(i32) -> i32>,
 }
 
-impl CardRenderConfig {
+impl<'a> CardRenderConfig<'a> {
     pub fn new(x: f32, y: f32, width: f32, height: f32, theme: Theme) -> Self {
         CardRenderConfig {
             x,
@@ -29,7 +30,7 @@ impl CardRenderConfig {
             hovering: false,
             scale: 1.0,
             theme,
-            player_strength: 0,
+            damage_preview: None,
         }
     }
     
@@ -48,8 +49,8 @@ impl CardRenderConfig {
         self
     }
     
-    pub fn with_player_strength(mut self, strength: i32) -> Self {
-        self.player_strength = strength;
+    pub fn with_damage_preview(mut self, preview: &'a dyn Fn(i32) -> i32) -> Self {
+        self.damage_preview = Some(preview);
         self
     }
 }
@@ -165,11 +166,11 @@ pub fn draw_card(
     let desc_y = final_y + final_height * 0.65;
     let desc_max_width = final_width - 20.0;
     
-    if config.player_strength != 0 && *card.card_type() == CardType::Attack {
-        draw_description_with_boosted_damage(
+    if let Some(preview) = config.damage_preview.filter(|_| *card.card_type() == CardType::Attack) {
+        draw_description_with_previewed_damage(
             canvas,
             card.description(),
-            config.player_strength,
+            preview,
             final_x + 10.0,
             desc_y,
             desc_max_width,
@@ -246,10 +247,10 @@ fn word_wrap(text: &str, max_width: f32, font_size: f32) -> String {
     lines.join("\n")
 }
 
-fn draw_description_with_boosted_damage(
+fn draw_description_with_previewed_damage(
     canvas: &mut Canvas,
     description: &str,
-    strength: i32,
+    preview: &dyn Fn(i32) -> i32,
     x: f32,
     y: f32,
     max_width: f32,
@@ -266,7 +267,14 @@ fn draw_description_with_boosted_damage(
         let full_match = cap.get(0).unwrap();
         let num_str = cap.get(1).unwrap().as_str();
         let num: i32 = num_str.parse().unwrap();
-        let boosted = num + strength;
+        let previewed = preview(num);
+        let previewed_color = if previewed > num {
+            Color::from_rgb(100, 255, 100)
+        } else if previewed < num {
+            Color::from_rgb(255, 100, 100)
+        } else {
+            default_color
+        };
         
         if full_match.start() > last_end {
             text.add(TextFragment {
@@ -278,8 +286,8 @@ fn draw_description_with_boosted_damage(
         }
         
         text.add(TextFragment {
-            text: boosted.to_string(),
-            color: Some(Color::from_rgb(100, 255, 100)),
+            text: previewed.to_string(),
+            color: Some(previewed_color),
             font: None,
             scale: Some(ggez::graphics::PxScale::from(font_size)),
         });
@@ -8,6 +8,7 @@ use crate::ui::rendering::theme::Theme;
 use crate::ui::rendering::assets::Assets;
 use crate::ui::rendering::card_renderer::{draw_card, CardRenderConfig};
 use crate::core::card::Card;
+use crate::core::rng::{GameRng, RngStream};
 use crate::cards;
 
 pub struct CardRewardScreen {
@@ -19,8 +20,7 @@ pub struct CardRewardScreen {
 }
 
 impl CardRewardScreen {
-    pub fn new(assets: Arc<Assets>) -> Self {
-        let mut rng = rand::thread_rng();
+    pub fn new(assets: Arc<Assets>, rng: &mut GameRng) -> Self {
         let available_cards = vec![
             cards::strike(0, false),
             cards::defend(0, false),
@@ -28,10 +28,12 @@ impl CardRewardScreen {
             cards::haste(0, false),
             cards::whirlwind(0, false),
             cards::barricade(0, false),
+            cards::disarm(0, false),
+            cards::dark_shackles(0, false),
         ];
 
         let card_choices: Vec<Card> = available_cards
-            .choose_multiple(&mut rng, 3)
+            .choose_multiple(rng.stream(RngStream::CardRewards), 3)
             .cloned()
             .collect();
 
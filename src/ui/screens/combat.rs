@@ -2,13 +2,32 @@ use ggez::graphics::{Canvas, Color, DrawParam, Mesh, Rect, Text};
 use ggez::input::mouse::MouseButton;
 use ggez::{Context, GameResult};
 use std::sync::Arc;
+use std::time::Duration;
 
-use crate::core::{GameState, EntityId, Player};
+use crate::core::{GameState, EntityId, EnemyId, Player, GameRng, PlayerAction, DamageKind, IntentKind};
 use crate::core::base_state::{State, StatusType};
 use crate::core::enemy::Enemy;
 use crate::core::card::{Card, CardTargeting};
+use crate::ai::{MctsConfig, MctsSearch};
 use super::super::rendering::{Theme, Assets, draw_card, CardRenderConfig};
 
+const ENEMY_WIDTH: f32 = 180.0;
+const ENEMY_HEIGHT: f32 = 180.0;
+const ENEMY_SPACING: f32 = 30.0;
+const ENEMY_Y: f32 = 200.0;
+/// Screen strip the enemies are centred in, right of the player.
+const ENEMY_AREA_X: f32 = 410.0;
+const ENEMY_AREA_WIDTH: f32 = 980.0;
+const ENEMY_FADE_SECONDS: f32 = 0.6;
+const ACTION_STEP_SECONDS: f32 = 0.25;
+/// Time the move suggestion may search for each frame, so the screen keeps drawing.
+const SEARCH_SLICE: Duration = Duration::from_millis(8);
+
+enum Suggestion {
+    Thinking(Box<MctsSearch>),
+    Ready(String),
+}
+
 pub struct CombatScreen {
     theme: Theme,
     assets: Arc<Assets>,
@@ -16,14 +35,27 @@ pub struct CombatScreen {
     
     selected_card_index: Option<usize>,
     hovering_card_index: Option<usize>,
-    hovering_enemy_index: Option<usize>,
+    hovering_enemy: Option<EnemyId>,
+    enemy_layout: Vec<(EnemyId, Rect)>,
+    fading_enemies: Vec<(EnemyId, Rect, f32)>,
     animation_timer: f32,
     current_animation_frame: usize,
+    suggestion: Option<Suggestion>,
+    action_timer: f32,
 }
 
 impl CombatScreen {
     pub fn new_with_state(mut game_state: GameState, assets: &Arc<Assets>) -> Self {
-        game_state.start_player_turn();
+        game_state.start_combat();
+        Self::resume_with_state(game_state, assets)
+    }
+    
+    /// Shows a combat that is already underway, such as one loaded from a save.
+    /// Everything from here on is recorded so the combat can be replayed.
+    pub fn resume_with_state(mut game_state: GameState, assets: &Arc<Assets>) -> Self {
+        game_state.start_recording();
+        game_state.set_deferred_resolution(true);
+        let enemy_layout = Self::compute_enemy_layout(&game_state);
         
         CombatScreen {
             theme: Theme::default(),
@@ -31,9 +63,13 @@ impl CombatScreen {
             game_state,
             selected_card_index: None,
             hovering_card_index: None,
-            hovering_enemy_index: None,
+            hovering_enemy: None,
+            enemy_layout,
+            fading_enemies: Vec::new(),
             animation_timer: 0.0,
             current_animation_frame: 0,
+            suggestion: None,
+            action_timer: 0.0,
         }
     }
     
@@ -41,6 +77,14 @@ impl CombatScreen {
         self.game_state.player()
     }
     
+    pub fn get_rng(&self) -> &GameRng {
+        self.game_state.rng()
+    }
+    
+    pub fn game_state(&self) -> &GameState {
+        &self.game_state
+    }
+    
     pub fn get_all_cards(&self) -> Vec<Card> {
         let mut all_cards = Vec::new();
         all_cards.extend(self.game_state.hand().iter().cloned());
@@ -51,15 +95,97 @@ impl CombatScreen {
     }
     
     pub fn update(&mut self, ctx: &mut Context) -> GameResult<CombatAction> {
-        self.animation_timer += ctx.time.delta().as_secs_f32();
+        let delta = ctx.time.delta().as_secs_f32();
+        self.animation_timer += delta;
         if self.animation_timer >= 0.15 {
             self.animation_timer = 0.0;
             self.current_animation_frame += 1;
         }
         
+        for (_, _, remaining) in &mut self.fading_enemies {
+            *remaining -= delta;
+        }
+        self.fading_enemies.retain(|(_, _, remaining)| *remaining > 0.0);
+        
+        // Played cards resolve one queued action at a time so each step is visible.
+        if self.game_state.has_pending_actions() {
+            self.action_timer += delta;
+            if self.action_timer >= ACTION_STEP_SECONDS {
+                self.action_timer = 0.0;
+                self.game_state.step_action();
+            }
+        } else {
+            self.action_timer = 0.0;
+        }
+        
+        if let Some(Suggestion::Thinking(search)) = &mut self.suggestion
+            && search.run_for(SEARCH_SLICE)
+        {
+            let plan = search.plan();
+            self.suggestion = Some(Suggestion::Ready(self.describe_plan(plan)));
+        }
+        
+        self.refresh_enemy_layout();
+        
         Ok(CombatAction::None)
     }
     
+    /// Screen rectangles of the living enemies, keyed by their stable id. Each
+    /// enemy is drawn at its field slot, so gaps left by the dead stay open,
+    /// and enemies shrink when the occupied slots would not fit the screen.
+    fn compute_enemy_layout(game_state: &GameState) -> Vec<(EnemyId, Rect)> {
+        let living_enemies: Vec<(EnemyId, usize)> = game_state
+            .enemies()
+            .iter()
+            .filter(|(_, e)| e.is_alive())
+            .filter_map(|(id, _)| game_state.enemy_slot(*id).map(|slot| (*id, slot)))
+            .collect();
+        
+        let first_slot = living_enemies.iter().map(|(_, slot)| *slot).min().unwrap_or(0);
+        let last_slot = living_enemies.iter().map(|(_, slot)| *slot).max().unwrap_or(0);
+        let columns = (last_slot - first_slot + 1) as f32;
+        
+        let fitted_width = (ENEMY_AREA_WIDTH - (columns - 1.0) * ENEMY_SPACING) / columns;
+        let enemy_size = ENEMY_WIDTH.min(fitted_width);
+        let total_width = columns * enemy_size + (columns - 1.0) * ENEMY_SPACING;
+        let start_x = ENEMY_AREA_X + (ENEMY_AREA_WIDTH - total_width) / 2.0;
+        let enemy_y = ENEMY_Y + (ENEMY_HEIGHT - enemy_size);
+        
+        living_enemies
+            .into_iter()
+            .map(|(id, slot)| {
+                let enemy_x = start_x + (slot - first_slot) as f32 * (enemy_size + ENEMY_SPACING);
+                (id, Rect::new(enemy_x, enemy_y, enemy_size, enemy_size))
+            })
+            .collect()
+    }
+    
+    /// Recomputes enemy positions after the roster changed. Enemies that left
+    /// the field keep their last position while they fade out.
+    fn refresh_enemy_layout(&mut self) {
+        let new_layout = Self::compute_enemy_layout(&self.game_state);
+        
+        for (id, rect) in &self.enemy_layout {
+            let still_present = new_layout.iter().any(|(new_id, _)| new_id == id);
+            if !still_present {
+                self.fading_enemies.push((*id, *rect, ENEMY_FADE_SECONDS));
+            }
+        }
+        
+        if self.hovering_enemy.is_some_and(|id| !new_layout.iter().any(|(new_id, _)| *new_id == id)) {
+            self.hovering_enemy = None;
+        }
+        
+        self.enemy_layout = new_layout;
+    }
+    
+    fn enemy_at(&self, x: f32, y: f32) -> Option<EnemyId> {
+        self.enemy_layout
+            .iter()
+            .find(|(_, rect)| x >= rect.x && x <= rect.x + rect.w && y >= rect.y && y <= rect.y + rect.h)
+            .map(|(id, _)| *id)
+    }
+    
     pub fn draw(&mut self, ctx: &mut Context, canvas: &mut Canvas) -> GameResult {
         self.draw_player(ctx, canvas)?;
         self.draw_enemies(ctx, canvas)?;
@@ -68,6 +194,7 @@ impl CombatScreen {
         self.draw_pile_info(ctx, canvas)?;
         self.draw_end_turn_button(ctx, canvas)?;
         self.draw_hero_ability_button(ctx, canvas)?;
+        self.draw_suggest_button(ctx, canvas)?;
         if self.game_state.is_combat_over() || !self.game_state.player().is_alive() {
             self.draw_game_over_overlay(ctx, canvas)?;
         }
@@ -136,7 +263,10 @@ impl CombatScreen {
         )?;
         canvas.draw(&fg_mesh, DrawParam::default());
         
-        let health_text = format!("{}/{}", player.get_current_health(), player.get_max_health());
+        let mut health_text = format!("{}/{}", player.get_current_health(), player.get_max_health());
+        if player.get_temp_hp() > 0 {
+            health_text.push_str(&format!(" (+{})", player.get_temp_hp()));
+        }
         let mut health_label = Text::new(health_text);
         health_label.set_scale(14.0);
         canvas.draw(&health_label, DrawParam::default().dest([player_x + 25.0, health_y + 3.0]).color(self.theme.text));
@@ -166,23 +296,11 @@ impl CombatScreen {
     }
     
     fn draw_player_statuses(&self, _ctx: &mut Context, canvas: &mut Canvas, x: f32, y: f32) -> GameResult {
-        let player = self.game_state.player();
-        let mut status_lines = Vec::new();
-        
-        let strength = player.get_status(&StatusType::Strength);
-        if strength != 0 {
-            status_lines.push(format!("Strength: {}{}", if strength > 0 { "+" } else { "" }, strength));
-        }
-        
-        let vulnerable = player.get_status(&StatusType::Vulnerable);
-        if vulnerable > 0 {
-            status_lines.push(format!("Vulnerable: {}", vulnerable));
-        }
-        
-        let weak = player.get_status(&StatusType::Weak);
-        if weak > 0 {
-            status_lines.push(format!("Weak: {}", weak));
-        }
+        let status_lines: Vec<String> = self.game_state.player()
+            .get_all_statuses()
+            .iter()
+            .map(|status| format!("{}: {}", self.status_name(&status.status_type), status.stacks))
+            .collect();
         
         if !status_lines.is_empty() {
             let status_text = status_lines.join("\n");
@@ -195,40 +313,56 @@ impl CombatScreen {
     }
     
     fn draw_enemies(&self, ctx: &mut Context, canvas: &mut Canvas) -> GameResult {
-        let enemies = self.game_state.enemies();
-        let living_enemies: Vec<(usize, &Box<dyn Enemy>)> = enemies
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| e.is_alive())
-            .collect();
-        
-        if living_enemies.is_empty() {
-            return Ok(());
+        for (enemy_id, rect, remaining) in &self.fading_enemies {
+            let alpha = (remaining / ENEMY_FADE_SECONDS).clamp(0.0, 1.0);
+            let animation_key = self.game_state.enemy(*enemy_id)
+                .or_else(|| self.game_state.dead_enemy(*enemy_id))
+                .map(Self::animation_key)
+                .unwrap_or_default();
+            
+            if self.assets.has_animation(&animation_key) {
+                if let Some(image) = self.assets.get_animation_frame(&animation_key, self.current_animation_frame) {
+                    canvas.draw(
+                        image,
+                        DrawParam::default()
+                            .dest([rect.x, rect.y])
+                            .scale([rect.w / image.width() as f32, rect.h / image.height() as f32])
+                            .color(Color::new(1.0, 1.0, 1.0, alpha))
+                    );
+                }
+            } else {
+                let mesh = Mesh::new_rectangle(
+                    ctx,
+                    ggez::graphics::DrawMode::fill(),
+                    *rect,
+                    Color::new(0.8, 0.2, 0.2, alpha),
+                )?;
+                canvas.draw(&mesh, DrawParam::default());
+            }
         }
         
-        let enemy_w = 180.0;
-        let enemy_h = 180.0;
-        let total_width = living_enemies.len() as f32 * enemy_w + (living_enemies.len().saturating_sub(1)) as f32 * 30.0;
-        let start_x = (1400.0 - total_width) / 2.0 + 200.0;
-        
-        for (visual_index, (actual_index, enemy)) in living_enemies.iter().enumerate() {
-            let enemy_x = start_x + (visual_index as f32) * (enemy_w + 30.0);
-            let enemy_y = 200.0;
-            
-            self.draw_enemy_intent(ctx, canvas, enemy, enemy_x, enemy_y - 50.0)?;
+        for (enemy_id, rect) in &self.enemy_layout {
+            let enemy = match self.game_state.enemy(*enemy_id) {
+                Some(enemy) => enemy,
+                None => continue,
+            };
+            let enemy_x = rect.x;
+            let enemy_y = rect.y;
+            let enemy_w = rect.w;
+            let enemy_h = rect.h;
             
-            let animation_key = "dragonling_idle";
-            let frame_count = self.assets.get_animation_frame_count(animation_key);
+            self.draw_enemy_intent(ctx, canvas, *enemy_id, enemy_x, enemy_y - 50.0)?;
+            let animation_key = Self::animation_key(enemy);
             
-            let hovering = self.hovering_enemy_index == Some(*actual_index);
+            let hovering = self.hovering_enemy == Some(*enemy_id);
             let scale = if hovering { 1.1 } else { 1.0 };
             let scaled_w = enemy_w * scale;
             let scaled_h = enemy_h * scale;
             let offset_x = (enemy_w - scaled_w) / 2.0;
             let offset_y = (enemy_h - scaled_h) / 2.0;
             
-            if frame_count > 0 {
-                if let Some(image) = self.assets.get_animation_frame(animation_key, self.current_animation_frame) {
+            if self.assets.has_animation(&animation_key) {
+                if let Some(image) = self.assets.get_animation_frame(&animation_key, self.current_animation_frame) {
                     canvas.draw(
                         image,
                         DrawParam::default()
@@ -257,25 +391,53 @@ impl CombatScreen {
             
             self.draw_enemy_health(ctx, canvas, enemy, enemy_x, enemy_y + enemy_h + 10.0, enemy_w)?;
             
-            self.draw_enemy_statuses(ctx, canvas, enemy, enemy_x, enemy_y + enemy_h + 35.0)?;
+            self.draw_enemy_statuses(ctx, canvas, *enemy_id, enemy, enemy_x, enemy_y + enemy_h + 35.0)?;
         }
         
         Ok(())
     }
     
-    fn draw_enemy_intent(&self, _ctx: &mut Context, canvas: &mut Canvas, enemy: &Box<dyn Enemy>, x: f32, y: f32) -> GameResult {
-        let turn_count = self.game_state.get_turn_count();
-        let intent = enemy.get_intent(turn_count);
-        let intent_text = intent.description();
+    fn animation_key(enemy: &dyn Enemy) -> String {
+        enemy.art_key().map(|art| format!("{}_idle", art)).unwrap_or_default()
+    }
+    
+    fn draw_enemy_intent(&self, ctx: &mut Context, canvas: &mut Canvas, enemy_id: EnemyId, x: f32, y: f32) -> GameResult {
+        let Some(preview) = self.game_state.enemy_intent_preview(enemy_id) else {
+            return Ok(());
+        };
         
-        let mut text = Text::new(intent_text);
-        text.set_scale(16.0);
-        canvas.draw(&text, DrawParam::default().dest([x + 40.0, y]).color(self.theme.enemy_health));
+        let (color, label) = match preview.kind {
+            IntentKind::Attack => (self.theme.enemy_health, "!"),
+            IntentKind::Defend => (self.theme.block_color, "B"),
+            IntentKind::Buff => (self.theme.strength_color, "+"),
+            IntentKind::Debuff => (self.theme.weak_color, "-"),
+            IntentKind::Unknown => (self.theme.text_secondary, "?"),
+        };
+        
+        let icon_radius = 14.0;
+        let icon_center = [x + 40.0 + icon_radius, y + icon_radius];
+        let icon = Mesh::new_circle(ctx, ggez::graphics::DrawMode::fill(), icon_center, icon_radius, 0.5, color)?;
+        canvas.draw(&icon, DrawParam::default());
+        
+        let mut icon_text = Text::new(label);
+        icon_text.set_scale(18.0);
+        canvas.draw(&icon_text, DrawParam::default().dest([icon_center[0] - 5.0, icon_center[1] - 9.0]).color(Color::WHITE));
+        
+        if let Some(damage) = preview.damage {
+            let amount = if preview.hits > 1 {
+                format!("{}x{}", damage, preview.hits)
+            } else {
+                damage.to_string()
+            };
+            let mut text = Text::new(amount);
+            text.set_scale(20.0);
+            canvas.draw(&text, DrawParam::default().dest([icon_center[0] + icon_radius + 6.0, y + 4.0]).color(color));
+        }
         
         Ok(())
     }
     
-    fn draw_enemy_health(&self, ctx: &mut Context, canvas: &mut Canvas, enemy: &Box<dyn Enemy>, x: f32, y: f32, width: f32) -> GameResult {
+    fn draw_enemy_health(&self, ctx: &mut Context, canvas: &mut Canvas, enemy: &dyn Enemy, x: f32, y: f32, width: f32) -> GameResult {
         let height = 20.0;
         
         let bg_rect = Rect::new(x, y, width, height);
@@ -323,22 +485,17 @@ impl CombatScreen {
         Ok(())
     }
     
-    fn draw_enemy_statuses(&self, _ctx: &mut Context, canvas: &mut Canvas, enemy: &Box<dyn Enemy>, x: f32, y: f32) -> GameResult {
-        let mut status_lines = Vec::new();
-        
-        let strength = enemy.get_status(&StatusType::Strength);
-        if strength != 0 {
-            status_lines.push(format!("Str: {}", strength));
-        }
-        
-        let vulnerable = enemy.get_status(&StatusType::Vulnerable);
-        if vulnerable > 0 {
-            status_lines.push(format!("Vuln: {}", vulnerable));
-        }
+    fn draw_enemy_statuses(&self, _ctx: &mut Context, canvas: &mut Canvas, enemy_id: EnemyId, enemy: &dyn Enemy, x: f32, y: f32) -> GameResult {
+        let mut status_lines: Vec<String> = enemy
+            .get_all_statuses()
+            .iter()
+            .map(|status| format!("{}: {}", self.status_name(&status.status_type), status.stacks))
+            .collect();
         
-        let weak = enemy.get_status(&StatusType::Weak);
-        if weak > 0 {
-            status_lines.push(format!("Weak: {}", weak));
+        for (owner, effect) in self.game_state.effects() {
+            if owner == EntityId::Enemy(enemy_id) {
+                status_lines.push(effect.ui_state().name);
+            }
         }
         
         if !status_lines.is_empty() {
@@ -351,6 +508,13 @@ impl CombatScreen {
         Ok(())
     }
     
+    fn status_name(&self, status_type: &StatusType) -> String {
+        self.game_state
+            .status_definition(status_type)
+            .map(|definition| definition.name.clone())
+            .unwrap_or_else(|| status_type.id().to_string())
+    }
+    
     fn draw_hand(&self, ctx: &mut Context, canvas: &mut Canvas) -> GameResult {
         let hand = self.game_state.hand();
         let card_width = 160.0;
@@ -359,14 +523,16 @@ impl CombatScreen {
         let start_x = 75.0;
         let start_y = 550.0;
         
-        let player_strength = self.game_state.player().get_status(&StatusType::Strength);
+        // Previews go through the real damage pipeline, against the hovered enemy if there is one.
+        let target = self.hovering_enemy.map(EntityId::Enemy);
+        let preview = |base: i32| self.game_state.calculate_damage(DamageKind::Attack, EntityId::Player, target, base).damage;
         
         for (i, card) in hand.iter().enumerate() {
             let x = start_x + (i as f32) * (card_width + card_spacing);
             let config = CardRenderConfig::new(x, start_y, card_width, card_height, self.theme.clone())
                 .with_selected(self.selected_card_index == Some(i))
                 .with_hovering(self.hovering_card_index == Some(i))
-                .with_player_strength(player_strength);
+                .with_damage_preview(&preview);
             
             draw_card(ctx, canvas, card, &config, &self.assets)?;
         }
@@ -496,6 +662,80 @@ impl CombatScreen {
         Ok(())
     }
     
+    fn draw_suggest_button(&self, ctx: &mut Context, canvas: &mut Canvas) -> GameResult {
+        let button_rect = self.get_suggest_button_rect();
+        
+        let button_mesh = Mesh::new_rectangle(
+            ctx,
+            ggez::graphics::DrawMode::fill(),
+            button_rect,
+            self.theme.button,
+        )?;
+        canvas.draw(&button_mesh, DrawParam::default());
+        
+        let border_mesh = Mesh::new_rectangle(
+            ctx,
+            ggez::graphics::DrawMode::stroke(2.0),
+            button_rect,
+            self.theme.card_border,
+        )?;
+        canvas.draw(&border_mesh, DrawParam::default());
+        
+        let mut text = Text::new("Suggest Move");
+        text.set_scale(16.0);
+        canvas.draw(&text, DrawParam::default().dest([button_rect.x + 50.0, button_rect.y + 9.0]).color(self.theme.text));
+        
+        let suggestion = match &self.suggestion {
+            Some(Suggestion::Thinking(_)) => Some("thinking..."),
+            Some(Suggestion::Ready(plan)) => Some(plan.as_str()),
+            None => None,
+        };
+        if let Some(suggestion) = suggestion {
+            let mut text = Text::new(format!("Suggested: {}", suggestion));
+            text.set_scale(18.0);
+            canvas.draw(&text, DrawParam::default().dest([75.0, 520.0]).color(Color::from_rgb(255, 220, 120)));
+        }
+        
+        Ok(())
+    }
+    
+    /// Starts the tree search on the current position; `update` runs it a slice at a time.
+    fn suggest_moves(&self) -> Suggestion {
+        let config = MctsConfig {
+            seed: self.game_state.seed() ^ self.game_state.get_turn_count() as u64,
+            ..MctsConfig::default()
+        };
+        Suggestion::Thinking(Box::new(MctsSearch::new(&self.game_state, &config)))
+    }
+    
+    fn describe_plan(&self, plan: Vec<PlayerAction>) -> String {
+        let mut preview = self.game_state.clone();
+        let mut steps = Vec::new();
+        for action in plan {
+            let step = match action {
+                PlayerAction::PlayCard { hand_index, target } => {
+                    let card_name = preview.hand().get(hand_index).map_or("?", |card| card.name()).to_string();
+                    match target {
+                        Some(EntityId::Enemy(id)) => {
+                            let enemy_name = preview.enemy(id).map_or("?", |enemy| enemy.get_name());
+                            format!("{} -> {}", card_name, enemy_name)
+                        }
+                        _ => card_name,
+                    }
+                }
+                PlayerAction::UseHeroAbility => "Hero Ability".to_string(),
+                PlayerAction::EndTurn => "End Turn".to_string(),
+            };
+            steps.push(step);
+            
+            if action == PlayerAction::EndTurn || preview.apply(action).is_err() {
+                break;
+            }
+        }
+        
+        steps.join(", ")
+    }
+    
     fn get_end_turn_button_rect(&self) -> Rect {
         Rect::new(1150.0, 510.0, 200.0, 50.0)
     }
@@ -504,6 +744,10 @@ impl CombatScreen {
         Rect::new(1150.0, 440.0, 200.0, 60.0)
     }
     
+    fn get_suggest_button_rect(&self) -> Rect {
+        Rect::new(1150.0, 395.0, 200.0, 35.0)
+    }
+    
     fn draw_game_over_overlay(&self, ctx: &mut Context, canvas: &mut Canvas) -> GameResult {
         let overlay_rect = Rect::new(0.0, 0.0, 1400.0, 800.0);
         let overlay_mesh = Mesh::new_rectangle(
@@ -548,16 +792,27 @@ impl CombatScreen {
             }
         }
         
+        if self.game_state.has_pending_actions() {
+            return Ok(CombatAction::None);
+        }
+        
+        let suggest_rect = self.get_suggest_button_rect();
+        if x >= suggest_rect.x && x <= suggest_rect.x + suggest_rect.w &&
+           y >= suggest_rect.y && y <= suggest_rect.y + suggest_rect.h {
+            self.suggestion = Some(self.suggest_moves());
+            return Ok(CombatAction::None);
+        }
+        
         let end_turn_rect = self.get_end_turn_button_rect();
         if x >= end_turn_rect.x && x <= end_turn_rect.x + end_turn_rect.w &&
            y >= end_turn_rect.y && y <= end_turn_rect.y + end_turn_rect.h {
             self.selected_card_index = None;
+            self.suggestion = None;
             
-            self.game_state.end_player_turn();
-            
-            self.game_state.execute_all_enemy_turns();
-            
-            self.game_state.start_player_turn();
+            if let Err(err) = self.game_state.apply(PlayerAction::EndTurn) {
+                println!("Failed to end turn: {}", err);
+            }
+            self.refresh_enemy_layout();
             
             return Ok(CombatAction::None);
         }
@@ -565,9 +820,10 @@ impl CombatScreen {
         let hero_ability_rect = self.get_hero_ability_button_rect();
         if x >= hero_ability_rect.x && x <= hero_ability_rect.x + hero_ability_rect.w &&
            y >= hero_ability_rect.y && y <= hero_ability_rect.y + hero_ability_rect.h {
-            if let Err(err) = self.game_state.use_hero_ability() {
+            if let Err(err) = self.game_state.apply(PlayerAction::UseHeroAbility) {
                 println!("Hero ability failed: {}", err);
             }
+            self.suggestion = None;
             return Ok(CombatAction::None);
         }
         
@@ -590,10 +846,12 @@ impl CombatScreen {
                     if needs_target {
                         return Ok(CombatAction::None);
                     } else {
-                        if let Err(err) = self.game_state.play_card(i, None) {
+                        if let Err(err) = self.game_state.apply(PlayerAction::PlayCard { hand_index: i, target: None }) {
                             println!("Failed to play card: {}", err);
                         }
                         self.selected_card_index = None;
+                        self.suggestion = None;
+                        self.refresh_enemy_layout();
                         return Ok(CombatAction::None);
                     }
                 } else {
@@ -603,34 +861,16 @@ impl CombatScreen {
             }
         }
         
-        if let Some(card_index) = self.selected_card_index {
-            let enemies = self.game_state.enemies();
-            let living_enemies: Vec<usize> = enemies
-                .iter()
-                .enumerate()
-                .filter(|(_, e)| e.is_alive())
-                .map(|(i, _)| i)
-                .collect();
-            
-            let enemy_w = 180.0;
-            let enemy_h = 180.0;
-            let total_width = living_enemies.len() as f32 * enemy_w + (living_enemies.len().saturating_sub(1)) as f32 * 30.0;
-            let start_x = (1400.0 - total_width) / 2.0 + 200.0;
-            
-            for (visual_index, actual_index) in living_enemies.iter().enumerate() {
-                let enemy_x = start_x + (visual_index as f32) * (enemy_w + 30.0);
-                let enemy_y = 200.0;
-                
-                if x >= enemy_x && x <= enemy_x + enemy_w &&
-                   y >= enemy_y && y <= enemy_y + enemy_h {
-                    let target = EntityId::Enemy(*actual_index);
-                    if let Err(err) = self.game_state.play_card(card_index, Some(target)) {
-                        println!("Failed to play card: {}", err);
-                    }
-                    self.selected_card_index = None;
-                    return Ok(CombatAction::None);
-                }
+        if let (Some(card_index), Some(enemy_id)) = (self.selected_card_index, self.enemy_at(x, y)) {
+            let target = EntityId::Enemy(enemy_id);
+            let action = PlayerAction::PlayCard { hand_index: card_index, target: Some(target) };
+            if let Err(err) = self.game_state.apply(action) {
+                println!("Failed to play card: {}", err);
             }
+            self.selected_card_index = None;
+            self.suggestion = None;
+            self.refresh_enemy_layout();
+            return Ok(CombatAction::None);
         }
         
         Ok(CombatAction::None)
@@ -655,30 +895,7 @@ impl CombatScreen {
             }
         }
         
-        let enemies = self.game_state.enemies();
-        let living_enemies: Vec<usize> = enemies
-            .iter()
-            .enumerate()
-            .filter(|(_, e)| e.is_alive())
-            .map(|(i, _)| i)
-            .collect();
-        
-        let enemy_w = 180.0;
-        let enemy_h = 180.0;
-        let total_width = living_enemies.len() as f32 * enemy_w + (living_enemies.len().saturating_sub(1)) as f32 * 30.0;
-        let start_x_enemies = (1400.0 - total_width) / 2.0 + 200.0;
-        
-        self.hovering_enemy_index = None;
-        for (visual_index, actual_index) in living_enemies.iter().enumerate() {
-            let enemy_x = start_x_enemies + (visual_index as f32) * (enemy_w + 30.0);
-            let enemy_y = 200.0;
-            
-            if x >= enemy_x && x <= enemy_x + enemy_w &&
-               y >= enemy_y && y <= enemy_y + enemy_h {
-                self.hovering_enemy_index = Some(*actual_index);
-                break;
-            }
-        }
+        self.hovering_enemy = self.enemy_at(x, y);
     }
 }
 
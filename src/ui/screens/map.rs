@@ -78,6 +78,26 @@ impl MapScreen {
         }
     }
 
+    /// Rebuilds the map of a saved run with every node before
+    /// `current_node_index` marked as completed.
+    pub fn resume(floor: usize, current_node_index: usize) -> Self {
+        let mut map = Self::new();
+        map.floor = floor;
+        map.current_node_index = current_node_index.min(map.nodes.len() - 1);
+        for node in map.nodes.iter_mut().take(map.current_node_index) {
+            node.completed = true;
+        }
+        map
+    }
+    
+    pub fn floor(&self) -> usize {
+        self.floor
+    }
+    
+    pub fn current_node_index(&self) -> usize {
+        self.current_node_index
+    }
+
     pub fn advance_floor(&mut self) {
         self.floor += 1;
         if self.current_node_index < self.nodes.len() {
@@ -6,14 +6,20 @@ use crate::ui::rendering::theme::Theme;
 
 pub struct MenuScreen {
     theme: Theme,
+    can_continue: bool,
 }
 
 impl MenuScreen {
     pub fn new() -> Self {
         MenuScreen {
             theme: Theme::default(),
+            can_continue: false,
         }
     }
+    
+    pub fn set_can_continue(&mut self, can_continue: bool) {
+        self.can_continue = can_continue;
+    }
 
     pub fn update(&mut self, _ctx: &mut Context) -> GameResult<MenuAction> {
         Ok(MenuAction::None)
@@ -39,7 +45,34 @@ impl MenuScreen {
         let subtitle_pos = [520.0, 230.0];
         canvas.draw(&subtitle, DrawParam::default().dest(subtitle_pos).color(self.theme.text_secondary));
 
-        let start_button_rect = Rect::new(450.0, 350.0, 500.0, 80.0);
+        let continue_button_rect = Rect::new(450.0, 350.0, 500.0, 80.0);
+        let continue_color = if self.can_continue {
+            self.theme.button
+        } else {
+            Color::from_rgb(40, 40, 50)
+        };
+        let continue_mesh = Mesh::new_rectangle(
+            ctx,
+            ggez::graphics::DrawMode::fill(),
+            continue_button_rect,
+            continue_color,
+        )?;
+        canvas.draw(&continue_mesh, DrawParam::default());
+
+        let continue_border = Mesh::new_rectangle(
+            ctx,
+            ggez::graphics::DrawMode::stroke(3.0),
+            continue_button_rect,
+            if self.can_continue { self.theme.card_border } else { Color::from_rgb(100, 100, 100) },
+        )?;
+        canvas.draw(&continue_border, DrawParam::default());
+
+        let mut continue_text = Text::new("CONTINUE");
+        continue_text.set_scale(32.0);
+        let continue_text_color = if self.can_continue { self.theme.text } else { Color::from_rgb(100, 100, 100) };
+        canvas.draw(&continue_text, DrawParam::default().dest([615.0, 380.0]).color(continue_text_color));
+
+        let start_button_rect = Rect::new(450.0, 460.0, 500.0, 80.0);
         let button_mesh = Mesh::new_rectangle(
             ctx,
             ggez::graphics::DrawMode::fill(),
@@ -58,9 +91,9 @@ impl MenuScreen {
 
         let mut button_text = Text::new("START NEW RUN");
         button_text.set_scale(32.0);
-        canvas.draw(&button_text, DrawParam::default().dest([570.0, 380.0]).color(self.theme.text));
+        canvas.draw(&button_text, DrawParam::default().dest([570.0, 490.0]).color(self.theme.text));
 
-        let quit_button_rect = Rect::new(450.0, 460.0, 500.0, 80.0);
+        let quit_button_rect = Rect::new(450.0, 570.0, 500.0, 80.0);
         let quit_button_mesh = Mesh::new_rectangle(
             ctx,
             ggez::graphics::DrawMode::fill(),
@@ -79,20 +112,27 @@ impl MenuScreen {
 
         let mut quit_text = Text::new("QUIT");
         quit_text.set_scale(32.0);
-        canvas.draw(&quit_text, DrawParam::default().dest([665.0, 490.0]).color(self.theme.text));
+        canvas.draw(&quit_text, DrawParam::default().dest([665.0, 600.0]).color(self.theme.text));
 
         Ok(())
     }
 
     pub fn mouse_button_down(&mut self, _ctx: &mut Context, button: MouseButton, x: f32, y: f32) -> GameResult<MenuAction> {
         if button == MouseButton::Left {
-            let start_button_rect = Rect::new(450.0, 350.0, 500.0, 80.0);
+            let continue_button_rect = Rect::new(450.0, 350.0, 500.0, 80.0);
+            if self.can_continue &&
+               x >= continue_button_rect.x && x <= continue_button_rect.x + continue_button_rect.w &&
+               y >= continue_button_rect.y && y <= continue_button_rect.y + continue_button_rect.h {
+                return Ok(MenuAction::Continue);
+            }
+            
+            let start_button_rect = Rect::new(450.0, 460.0, 500.0, 80.0);
             if x >= start_button_rect.x && x <= start_button_rect.x + start_button_rect.w &&
                y >= start_button_rect.y && y <= start_button_rect.y + start_button_rect.h {
                 return Ok(MenuAction::StartRun);
             }
             
-            let quit_button_rect = Rect::new(450.0, 460.0, 500.0, 80.0);
+            let quit_button_rect = Rect::new(450.0, 570.0, 500.0, 80.0);
             if x >= quit_button_rect.x && x <= quit_button_rect.x + quit_button_rect.w &&
                y >= quit_button_rect.y && y <= quit_button_rect.y + quit_button_rect.h {
                 return Ok(MenuAction::Quit);
@@ -104,6 +144,7 @@ impl MenuScreen {
 
 pub enum MenuAction {
     None,
+    Continue,
     StartRun,
     Quit,
 }
@@ -0,0 +1,131 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use SlayTheSpire::cards::card_effects::DamageEffect;
+use SlayTheSpire::cards::{strike, whirlwind};
+use SlayTheSpire::core::{Action, EnemyId, EntityId, GameState, PlayerAction};
+use SlayTheSpire::core::base_state::{State, StatusType};
+
+mod common;
+use common::create_game;
+
+type Trace = Rc<RefCell<Vec<String>>>;
+
+/// Writes its name to a shared trace when it resolves, then queues its
+/// follow-ups on top of the queue.
+#[derive(Clone, Debug)]
+struct Step {
+    name: &'static str,
+    trace: Trace,
+    then: Vec<Step>,
+}
+
+impl Step {
+    fn new(name: &'static str, trace: &Trace) -> Self {
+        Step { name, trace: trace.clone(), then: Vec::new() }
+    }
+}
+
+impl Action for Step {
+    fn resolve(&self, game_state: &mut GameState, source: EntityId, targets: &[EntityId], _energy_spent: Option<i32>) {
+        self.trace.borrow_mut().push(self.name.to_string());
+        for step in self.then.iter().rev() {
+            game_state.queue_action_top(Box::new(step.clone()), source, targets, None);
+        }
+    }
+    
+    fn description(&self) -> String {
+        self.name.to_string()
+    }
+    
+    fn clone_box(&self) -> Box<dyn Action> {
+        Box::new(self.clone())
+    }
+}
+
+#[test]
+fn test_follow_ups_on_top_run_before_later_actions() {
+    let trace = Trace::default();
+    let mut game = create_game(&["dragonling"], 1);
+    let mut first = Step::new("first", &trace);
+    first.then = vec![Step::new("first.a", &trace), Step::new("first.b", &trace)];
+    
+    game.queue_action_bottom(Box::new(first), EntityId::Player, &[], None);
+    game.queue_action_bottom(Box::new(Step::new("second", &trace)), EntityId::Player, &[], None);
+    game.resolve_actions();
+    
+    assert_eq!(*trace.borrow(), vec!["first", "first.a", "first.b", "second"]);
+    assert!(!game.has_pending_actions());
+}
+
+#[test]
+fn test_deferred_card_play_resolves_one_step_at_a_time() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_player_turn();
+    game.set_deferred_resolution(true);
+    game.add_card_to_hand(strike(100, false));
+    let hp_before = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    
+    game.apply(PlayerAction::PlayCard { hand_index: 0, target: Some(EntityId::Enemy(EnemyId(0))) }).unwrap();
+    
+    assert_eq!(game.pending_actions().len(), 2);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), hp_before);
+    assert!(game.apply(PlayerAction::UseHeroAbility).is_err());
+    
+    game.step_action().unwrap();
+    assert!(game.enemy(EnemyId(0)).unwrap().get_current_health() < hp_before);
+    assert!(game.discard_pile().is_empty());
+    
+    game.step_action().unwrap();
+    assert_eq!(game.discard_pile().len(), 1);
+    assert!(game.step_action().is_none());
+}
+
+#[test]
+fn test_whirlwind_hits_are_separate_steps_with_strength() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
+    game.add_card_to_hand(whirlwind(100, false));
+    let energy = game.player().get_energy();
+    
+    game.set_deferred_resolution(true);
+    game.play_card(0, None).unwrap();
+    game.step_action().unwrap();
+    assert_eq!(game.pending_actions().len() as i32, energy + 1);
+    
+    game.resolve_actions();
+    for id in [EnemyId(0), EnemyId(1)] {
+        assert_eq!(game.enemy(id).unwrap().get_current_health(), 50 - 7 * energy);
+    }
+}
+
+#[test]
+fn test_actions_on_dead_targets_are_skipped() {
+    let trace = Trace::default();
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    let first = EntityId::Enemy(EnemyId(0));
+    let second = EntityId::Enemy(EnemyId(1));
+    
+    game.queue_action_bottom(Box::new(DamageEffect { amount: 100 }), EntityId::Player, &[first], None);
+    game.queue_action_bottom(Box::new(Step::new("on dead", &trace)), EntityId::Player, &[first], None);
+    game.queue_action_bottom(Box::new(Step::new("on both", &trace)), EntityId::Player, &[first, second], None);
+    game.queue_action_bottom(Box::new(Step::new("from dead", &trace)), first, &[EntityId::Player], None);
+    game.resolve_actions();
+    
+    assert!(game.enemy(EnemyId(0)).is_none());
+    assert_eq!(*trace.borrow(), vec!["on both"]);
+}
+
+#[test]
+fn test_enemy_intents_resolve_through_the_queue() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_player_turn();
+    let intent = game.enemy_intent(EnemyId(0)).unwrap().clone();
+    let hp_before = game.player().get_current_health();
+    
+    game.execute_enemy_intent(EnemyId(0), &intent, &[EntityId::Player]);
+    
+    assert!(!game.has_pending_actions());
+    assert_eq!(game.player().get_current_health(), hp_before - 8);
+}
@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use SlayTheSpire::ai::{MctsConfig, MctsSearch, evaluate, suggest_turn};
+use SlayTheSpire::core::{EnemyId, EntityId, PlayerAction};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::core::card::Card;
+use SlayTheSpire::cards::{defend, strike};
+
+mod common;
+use common::create_game_with_deck;
+
+/// Five Strikes and five Defends.
+fn deck() -> Vec<Card> {
+    (1..=10)
+        .map(|i| if i % 2 == 0 { strike(i, false) } else { defend(i, false) })
+        .collect()
+}
+
+fn config(seed: u64) -> MctsConfig {
+    MctsConfig {
+        iterations: 300,
+        time_budget: Duration::from_secs(30),
+        seed,
+        ..MctsConfig::default()
+    }
+}
+
+#[test]
+fn test_suggested_plan_is_playable() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    game.start_player_turn();
+    
+    let plan = suggest_turn(&game, &config(1));
+    
+    assert!(!plan.is_empty());
+    for action in &plan {
+        game.apply(*action).unwrap();
+    }
+}
+
+#[test]
+fn test_suggestion_finishes_off_weak_enemy() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    game.start_player_turn();
+    game.enemy_mut(EnemyId(1)).unwrap().set_health(4);
+    game.add_card_to_hand(strike(99, false));
+    
+    let plan = suggest_turn(&game, &config(2));
+    let target = Some(EntityId::Enemy(EnemyId(1)));
+    
+    let kills_weak_enemy = plan.iter().any(|action| matches!(
+        action,
+        PlayerAction::PlayCard { target: t, .. } if *t == target
+    ));
+    assert!(kills_weak_enemy, "plan was {:?}", plan);
+}
+
+#[test]
+fn test_search_is_deterministic_for_a_seed() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    game.start_player_turn();
+    
+    assert_eq!(suggest_turn(&game, &config(7)), suggest_turn(&game, &config(7)));
+}
+
+#[test]
+fn test_search_in_slices_matches_one_go() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    game.start_player_turn();
+    game.start_recording();
+    
+    let mut search = MctsSearch::new(&game, &config(7));
+    let mut slices = 0;
+    while !search.run_for(Duration::from_micros(200)) {
+        slices += 1;
+    }
+    
+    assert!(slices > 1);
+    assert_eq!(search.plan(), suggest_turn(&game, &config(7)));
+}
+
+#[test]
+fn test_evaluate_orders_outcomes() {
+    let ongoing = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    
+    let mut won = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    for id in won.enemy_ids() {
+        won.enemy_mut(id).unwrap().set_health(0);
+    }
+    
+    let mut lost = create_game_with_deck(&["dragonling", "dragonling"], deck(), 3);
+    lost.player_mut().set_health(0);
+    
+    assert!(evaluate(&won) > evaluate(&ongoing));
+    assert!(evaluate(&ongoing) > evaluate(&lost));
+    assert_eq!(evaluate(&lost), 0.0);
+}
@@ -0,0 +1,185 @@
+use std::path::Path;
+
+use SlayTheSpire::cards::defend;
+use SlayTheSpire::core::{DamageKind, EnemyId, EntityId, GameState, StatusType};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::enemies::{data_enemy_ids, EnemyDefinition};
+use SlayTheSpire::save::default_registry;
+
+mod common;
+use common::create_game;
+
+const FIRST: EntityId = EntityId::Enemy(EnemyId(0));
+
+fn next_round(game: &mut GameState) {
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    game.start_player_turn();
+}
+
+fn next_move(game: &GameState, enemy_id: EnemyId) -> String {
+    game.enemy_intent(enemy_id).and_then(|intent| intent.move_name()).unwrap_or_default().to_string()
+}
+
+fn power_ids(game: &GameState, owner: EntityId) -> Vec<&'static str> {
+    game.effects().filter(|(o, _)| *o == owner).map(|(_, effect)| effect.id()).collect()
+}
+
+#[test]
+fn test_every_enemy_loads() {
+    let registry = default_registry();
+    let ids = data_enemy_ids();
+    for id in ["cultist", "jaw_worm", "red_louse", "green_louse", "acid_slime_l", "spike_slime_l", "gremlin_nob", "lagavulin", "slime_boss"] {
+        assert!(ids.iter().any(|known| known == id), "missing {}", id);
+    }
+    
+    for id in ids {
+        assert!(registry.create_enemy(&id).unwrap().get_max_health() > 0);
+    }
+}
+
+#[test]
+fn test_every_enemy_has_its_own_art() {
+    let registry = default_registry();
+    
+    for id in data_enemy_ids() {
+        let enemy = registry.create_enemy(&id).unwrap();
+        let art = enemy.art_key().unwrap_or_else(|| panic!("{} has no art key", id));
+        let frame = format!("assets/enemies/art/{}/idle1.png", art);
+        assert!(Path::new(&frame).exists(), "{} has no {}", id, frame);
+    }
+}
+
+#[test]
+fn test_cultist_gains_ritual_and_grows_from_its_second_turn() {
+    let mut game = create_game(&["cultist"], 3);
+    game.start_combat();
+    assert_eq!(next_move(&game, EnemyId(0)), "incantation");
+    
+    next_round(&mut game);
+    assert_eq!(power_ids(&game, FIRST), ["ritual"]);
+    assert_eq!(game.get_status(FIRST, StatusType::STRENGTH), 0);
+    
+    next_round(&mut game);
+    assert_eq!(game.get_status(FIRST, StatusType::STRENGTH), 3);
+    assert_eq!(game.player().get_current_health(), 94);
+}
+
+#[test]
+fn test_lice_start_curled_up() {
+    let mut game = create_game(&["red_louse", "green_louse"], 3);
+    game.start_combat();
+    
+    assert_eq!(power_ids(&game, FIRST), ["curl_up"]);
+    assert_eq!(power_ids(&game, EntityId::Enemy(EnemyId(1))), ["curl_up"]);
+}
+
+#[test]
+fn test_gremlin_nob_enrages_against_skills() {
+    let mut game = create_game(&["gremlin_nob"], 3);
+    game.start_combat();
+    next_round(&mut game);
+    
+    game.add_card_to_hand(defend(100, false));
+    game.play_card(game.hand().len() - 1, Some(EntityId::Player)).unwrap();
+    assert_eq!(game.get_status(FIRST, StatusType::STRENGTH), 2);
+}
+
+#[test]
+fn test_lagavulin_sleeps_three_turns_then_attacks() {
+    let mut game = create_game(&["lagavulin"], 3);
+    game.start_combat();
+    
+    for _ in 0..3 {
+        assert_eq!(next_move(&game, EnemyId(0)), "sleep");
+        next_round(&mut game);
+    }
+    assert_eq!(next_move(&game, EnemyId(0)), "attack");
+    assert_eq!(game.player().get_current_health(), 100);
+}
+
+#[test]
+fn test_lagavulin_wakes_early_when_hurt() {
+    let mut game = create_game(&["lagavulin"], 3);
+    game.start_combat();
+    next_round(&mut game);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 8);
+    
+    game.apply_damage(DamageKind::Attack, EntityId::Player, FIRST, 10);
+    next_round(&mut game);
+    
+    assert_eq!(next_move(&game, EnemyId(0)), "attack");
+    next_round(&mut game);
+    assert_eq!(game.player().get_current_health(), 82);
+    assert_eq!(next_move(&game, EnemyId(0)), "attack");
+}
+
+#[test]
+fn test_large_slime_splits_at_half_health() {
+    let mut game = create_game(&["acid_slime_l"], 3);
+    game.start_combat();
+    game.apply_damage(DamageKind::Attack, EntityId::Player, FIRST, 30);
+    assert_ne!(next_move(&game, EnemyId(0)), "split");
+    
+    game.apply_damage(DamageKind::Attack, EntityId::Player, FIRST, 6);
+    assert_eq!(next_move(&game, EnemyId(0)), "split");
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(game.player().get_current_health(), 100);
+    assert_eq!(game.enemy_ids(), [EnemyId(1), EnemyId(2)]);
+    assert!(game.dead_enemy(EnemyId(0)).is_some());
+    for id in game.enemy_ids() {
+        let slime = game.enemy(id).unwrap();
+        assert_eq!(slime.id(), "acid_slime_m");
+        assert_eq!((slime.get_current_health(), slime.get_max_health()), (30, 30));
+    }
+    
+    game.start_player_turn();
+    assert!(game.enemy_ids().into_iter().all(|id| game.enemy_intent(id).is_some()));
+    assert!(!game.is_combat_over());
+}
+
+#[test]
+fn test_slime_boss_splits_into_large_slimes_that_split_again() {
+    let mut game = create_game(&["slime_boss"], 3);
+    game.start_combat();
+    game.apply_damage(DamageKind::Attack, EntityId::Player, FIRST, 80);
+    next_round(&mut game);
+    
+    let kinds: Vec<&str> = game.enemies().iter().map(|(_, enemy)| enemy.id()).collect();
+    assert_eq!(kinds, ["acid_slime_l", "spike_slime_l"]);
+    assert_eq!(game.enemy(EnemyId(1)).unwrap().get_current_health(), 60);
+    assert_eq!(power_ids(&game, EntityId::Enemy(EnemyId(1))), ["split"]);
+    assert_eq!(power_ids(&game, FIRST), Vec::<&str>::new());
+}
+
+#[test]
+fn test_forced_split_survives_a_save() {
+    let mut game = create_game(&["spike_slime_l"], 3);
+    game.start_combat();
+    game.apply_damage(DamageKind::Attack, EntityId::Player, FIRST, 40);
+    
+    let mut restored = GameState::from_snapshot(game.snapshot(), &default_registry()).unwrap();
+    assert_eq!(next_move(&restored, EnemyId(0)), "split");
+    
+    restored.end_player_turn();
+    restored.execute_all_enemy_turns();
+    assert_eq!(restored.living_enemy_count(), 2);
+}
+
+#[test]
+fn test_splits_into_unknown_enemies_are_rejected() {
+    let json = r#"{
+        "name": "Typo Slime",
+        "max_health": 20,
+        "powers": [{ "power": "split" }],
+        "moves": {
+            "split": { "kind": "unknown", "description": "Split", "actions": [{ "type": "split", "into": ["acid_slime_mm"] }] }
+        },
+        "ai": { "pattern": { "type": "cycle", "moves": ["split"] } }
+    }"#;
+    assert!(EnemyDefinition::from_json("typo_slime", json).is_err());
+    assert!(EnemyDefinition::from_json("acid_slime_m", &json.replace("acid_slime_mm", "acid_slime_m")).is_err());
+    assert!(EnemyDefinition::from_json("typo_slime", &json.replace("acid_slime_mm", "acid_slime_m")).is_ok());
+}
@@ -1,5 +1,5 @@
-use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, State, Enemy};
-use SlayTheSpire::enemies::Dragonling;
+use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, EnemyId, State, Enemy};
+use SlayTheSpire::enemies::DataEnemy;
 use SlayTheSpire::cards::{strike, defend, inflame, barricade, whirlwind, haste};
 
 #[test]
@@ -21,16 +21,16 @@ fn test_upgraded_card() {
 #[test]
 fn test_strike_deals_damage() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
     let strike_card = strike(100, false);
     game.add_card_to_hand(strike_card);
     
-    let enemy_hp_before = game.enemies()[0].get_current_health();
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
-    let enemy_hp_after = game.enemies()[0].get_current_health();
+    let enemy_hp_before = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
+    let enemy_hp_after = game.enemy(EnemyId(0)).unwrap().get_current_health();
     
     assert_eq!(enemy_hp_before - enemy_hp_after, 6);
 }
@@ -38,7 +38,7 @@ fn test_strike_deals_damage() {
 #[test]
 fn test_defend_gains_block() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -53,7 +53,7 @@ fn test_defend_gains_block() {
 #[test]
 fn test_upgraded_defend_more_block() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -67,7 +67,7 @@ fn test_upgraded_defend_more_block() {
 #[test]
 fn test_exhaust_card() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -75,7 +75,7 @@ fn test_exhaust_card() {
     assert!(haste_card.exhaust());
     game.add_card_to_hand(haste_card);
     
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
     
     assert_eq!(game.exhaust_pile().len(), 1);
     assert_eq!(game.discard_pile().len(), 0);
@@ -85,8 +85,8 @@ fn test_exhaust_card() {
 fn test_x_cost_whirlwind() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
     let enemies = vec![
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
     ];
     let mut game = GameState::new(player, enemies);
     
@@ -98,16 +98,16 @@ fn test_x_cost_whirlwind() {
     game.play_card(0, None).unwrap();
     
     assert_eq!(game.player().get_energy(), 0);
-    assert_eq!(game.enemies()[0].get_current_health(), 50 - (5 * energy_before));
-    assert_eq!(game.enemies()[1].get_current_health(), 50 - (5 * energy_before));
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 50 - (5 * energy_before));
+    assert_eq!(game.enemy(EnemyId(1)).unwrap().get_current_health(), 50 - (5 * energy_before));
 }
 
 #[test]
 fn test_card_targeting() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
     let enemies = vec![
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
     ];
     let mut game = GameState::new(player, enemies);
     
@@ -115,8 +115,8 @@ fn test_card_targeting() {
     let strike_card = strike(100, false);
     game.add_card_to_hand(strike_card);
     
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
     
-    assert_eq!(game.enemies()[0].get_current_health(), 44);
-    assert_eq!(game.enemies()[1].get_current_health(), 50);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 44);
+    assert_eq!(game.enemy(EnemyId(1)).unwrap().get_current_health(), 50);
 }
@@ -0,0 +1,65 @@
+use std::rc::Rc;
+
+use SlayTheSpire::core::{EnemyId, EntityId, PlayerAction};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::cards::{strike, starting_deck};
+
+mod common;
+use common::create_game_with_deck;
+
+#[test]
+fn test_cloned_card_shares_definition() {
+    let card = strike(1, false);
+    let copy = card.clone();
+    
+    assert!(Rc::ptr_eq(card.definition(), copy.definition()));
+    assert_eq!(copy.instance_id(), 1);
+}
+
+#[test]
+fn test_clone_shares_piles_until_changed() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], starting_deck(), 5);
+    game.start_player_turn();
+    let turn = game.get_turn_count();
+    let branch = game.clone();
+    
+    assert_eq!(branch.hand().as_ptr(), game.hand().as_ptr());
+    assert_eq!(branch.draw_pile().as_ptr(), game.draw_pile().as_ptr());
+    
+    game.apply(PlayerAction::EndTurn).unwrap();
+    
+    assert_ne!(branch.hand().as_ptr(), game.hand().as_ptr());
+    assert_eq!(branch.get_turn_count(), turn);
+    assert_eq!(branch.hand().len(), 5);
+    assert_eq!(branch.player().get_current_health(), 100);
+}
+
+#[test]
+fn test_clone_is_independent() {
+    let mut game = create_game_with_deck(&["dragonling", "dragonling"], starting_deck(), 5);
+    game.start_player_turn();
+    let mut branch = game.clone();
+    
+    let target = EntityId::Enemy(EnemyId(0));
+    let strike_index = branch.find_in_hand(|card| card.id() == "strike");
+    if let Some(hand_index) = strike_index {
+        branch.apply(PlayerAction::PlayCard { hand_index, target: Some(target) }).unwrap();
+    }
+    branch.apply(PlayerAction::EndTurn).unwrap();
+    
+    let original_enemy = game.enemy(EnemyId(0)).unwrap();
+    assert_eq!(original_enemy.get_current_health(), original_enemy.get_max_health());
+    assert_eq!(game.hand().len(), 5);
+    assert_eq!(game.player().get_energy(), 3);
+    
+    // The same seed and inputs replay identically on a second clone.
+    let mut replay = game.clone();
+    if let Some(hand_index) = strike_index {
+        replay.apply(PlayerAction::PlayCard { hand_index, target: Some(target) }).unwrap();
+    }
+    replay.apply(PlayerAction::EndTurn).unwrap();
+    assert_eq!(
+        serde_json::to_string(&replay.snapshot()).unwrap(),
+        serde_json::to_string(&branch.snapshot()).unwrap()
+    );
+}
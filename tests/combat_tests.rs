@@ -1,11 +1,11 @@
-use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, State, StatusType, Enemy};
-use SlayTheSpire::enemies::Dragonling;
+use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, EnemyId, State, StatusType, Enemy};
+use SlayTheSpire::enemies::DataEnemy;
 use SlayTheSpire::cards::{strike, defend};
 
 #[test]
 fn test_energy_system() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -13,7 +13,7 @@ fn test_energy_system() {
     
     let strike_card = strike(100, false);
     game.add_card_to_hand(strike_card);
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
     
     assert_eq!(game.player().get_energy(), 2);
 }
@@ -21,7 +21,7 @@ fn test_energy_system() {
 #[test]
 fn test_insufficient_energy() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -30,14 +30,14 @@ fn test_insufficient_energy() {
     let strike_card = strike(100, false);
     game.add_card_to_hand(strike_card);
     
-    let result = game.play_card(0, Some(EntityId::Enemy(0)));
+    let result = game.play_card(0, Some(EntityId::Enemy(EnemyId(0))));
     assert!(result.is_err());
 }
 
 #[test]
 fn test_block_absorbs_damage() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -47,7 +47,7 @@ fn test_block_absorbs_damage() {
     
     assert_eq!(game.player().get_block(), 5);
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 3);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 3);
     
     assert_eq!(game.player().get_block(), 2);
     assert_eq!(game.player().get_current_health(), 100);
@@ -56,7 +56,7 @@ fn test_block_absorbs_damage() {
 #[test]
 fn test_damage_exceeds_block() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -66,7 +66,7 @@ fn test_damage_exceeds_block() {
     
     assert_eq!(game.player().get_block(), 5);
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 10);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 10);
     
     assert_eq!(game.player().get_block(), 0);
     assert_eq!(game.player().get_current_health(), 95);
@@ -75,7 +75,7 @@ fn test_damage_exceeds_block() {
 #[test]
 fn test_draw_and_discard() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let deck = vec![strike(1, false), strike(2, false), defend(3, false)];
     let mut game = GameState::new_with_deck(player, enemies, deck);
     
@@ -96,7 +96,7 @@ fn test_draw_and_discard() {
 #[test]
 fn test_shuffle_when_draw_pile_empty() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let deck = vec![strike(1, false), strike(2, false)];
     let mut game = GameState::new_with_deck(player, enemies, deck);
     
@@ -113,31 +113,31 @@ fn test_shuffle_when_draw_pile_empty() {
 #[test]
 fn test_vulnerable_increases_damage() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 10);
-    assert_eq!(game.enemies()[0].get_current_health(), 40);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 10);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 40);
     
-    game.add_status(EntityId::Enemy(0), StatusType::Vulnerable, 1);
+    game.add_status(EntityId::Enemy(EnemyId(0)), StatusType::VULNERABLE, 1);
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 10);
-    assert_eq!(game.enemies()[0].get_current_health(), 25);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 10);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 25);
 }
 
 #[test]
 fn test_weak_reduces_damage() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 10);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 10);
     let hp_after_normal = game.player().get_current_health();
     assert_eq!(hp_after_normal, 90);
     
-    game.add_status(EntityId::Enemy(0), StatusType::Weak, 1);
+    game.add_status(EntityId::Enemy(EnemyId(0)), StatusType::WEAK, 1);
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 10);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 10);
     let hp_after_weak = game.player().get_current_health();
     assert_eq!(hp_after_weak, 83); // 10 * 0.75 = 7.5, floor to 7 damage
 }
@@ -145,42 +145,70 @@ fn test_weak_reduces_damage() {
 #[test]
 fn test_strength_increases_damage() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
-    game.add_status(EntityId::Player, StatusType::Strength, 3);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 3);
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 10);
-    assert_eq!(game.enemies()[0].get_current_health(), 37);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 10);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 37);
 }
 
 #[test]
 fn test_death_removes_enemies() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
     let enemies = vec![
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
     ];
     let mut game = GameState::new(player, enemies);
     
     assert_eq!(game.enemies().len(), 2);
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 999);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 999);
     game.remove_dead_enemies();
     
     assert_eq!(game.enemies().len(), 1);
     assert_eq!(game.living_enemy_count(), 1);
+    assert_eq!(game.graveyard().len(), 1);
+    assert!(game.dead_enemy(EnemyId(0)).is_some());
+}
+
+#[test]
+fn test_enemy_ids_survive_deaths() {
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let enemies = vec![
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+    ];
+    let mut game = GameState::new(player, enemies);
+    
+    game.start_player_turn();
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 999);
+    game.remove_dead_enemies();
+    
+    game.add_card_to_hand(strike(100, false));
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(2)))).unwrap();
+    
+    assert_eq!(game.enemy(EnemyId(1)).unwrap().get_current_health(), 50);
+    assert_eq!(game.enemy(EnemyId(2)).unwrap().get_current_health(), 44);
+    assert!(game.enemy(EnemyId(0)).is_none());
+    
+    game.add_card_to_hand(strike(101, false));
+    let result = game.play_card(0, Some(EntityId::Enemy(EnemyId(0))));
+    assert!(result.is_err());
 }
 
 #[test]
 fn test_combat_over_when_all_enemies_dead() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     assert!(!game.is_combat_over());
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 999);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 999);
     
     assert!(game.are_all_enemies_dead());
     assert!(game.is_combat_over());
@@ -189,12 +217,12 @@ fn test_combat_over_when_all_enemies_dead() {
 #[test]
 fn test_combat_over_when_player_dead() {
     let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 10);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     assert!(!game.is_combat_over());
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 999);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 999);
     
     assert!(game.is_player_dead());
     assert!(game.is_combat_over());
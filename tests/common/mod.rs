@@ -0,0 +1,29 @@
+#![allow(dead_code)]
+
+use SlayTheSpire::core::{Enemy, EnemyId, EntityId, GameRng, GameState, Player, STSClass};
+use SlayTheSpire::core::card::Card;
+use SlayTheSpire::enemies::DataEnemy;
+
+pub const ENEMY: EntityId = EntityId::Enemy(EnemyId(0));
+
+pub fn player(max_health: i32) -> Player {
+    Player::new(STSClass::Ironclad, "TestHero".to_string(), max_health)
+}
+
+pub fn enemy(id: &str) -> Box<dyn Enemy> {
+    Box::new(DataEnemy::new(id))
+}
+
+/// A 100 HP Ironclad with an empty deck against the given data enemies.
+pub fn create_game(enemy_ids: &[&str], seed: u64) -> GameState {
+    create_game_with_deck(enemy_ids, Vec::new(), seed)
+}
+
+pub fn create_game_with_deck(enemy_ids: &[&str], deck: Vec<Card>, seed: u64) -> GameState {
+    create_game_with_player(player(100), enemy_ids, deck, seed)
+}
+
+pub fn create_game_with_player(player: Player, enemy_ids: &[&str], deck: Vec<Card>, seed: u64) -> GameState {
+    let enemies = enemy_ids.iter().map(|id| enemy(id)).collect();
+    GameState::new_with_rng(player, enemies, deck, GameRng::new(seed))
+}
@@ -0,0 +1,133 @@
+use SlayTheSpire::core::{DamageKind, Effect, EffectUIState, EnemyId, EntityId};
+use SlayTheSpire::core::{GameEvent, GameState, PlayerAction, StatusType};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::cards::strike;
+
+mod common;
+use common::{create_game, ENEMY};
+
+/// Rupture-style power: gains Strength whenever its owner loses HP outside of combat damage.
+#[derive(Clone, Debug)]
+struct GainStrengthOnHpLoss;
+
+impl Effect for GainStrengthOnHpLoss {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        if let GameEvent::DamageDealt { target, amount, kind: DamageKind::HpLoss, .. } = event
+            && *target == owner && *amount > 0
+        {
+            game_state.add_status(owner, StatusType::STRENGTH, 1);
+        }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState { name: "Rupture".to_string(), description: String::new(), counters: vec![] }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "rupture"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+}
+
+/// Flame Barrier-style retaliation: whenever its owner is attacked, hits back.
+#[derive(Clone, Debug)]
+struct Retaliate(i32);
+
+impl Effect for Retaliate {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        if let GameEvent::DamageDealt { source, target, kind: DamageKind::Attack, .. } = event
+            && *target == owner && *source != owner
+        {
+            game_state.apply_damage(DamageKind::Thorns, owner, *source, self.0);
+        }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState { name: "Retaliate".to_string(), description: String::new(), counters: vec![] }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "retaliate"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+}
+
+#[test]
+fn test_hp_loss_ignores_block_and_strength() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.player_mut().set_block(10);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 3);
+    game.add_status(EntityId::Player, StatusType::VULNERABLE, 2);
+    
+    let result = game.apply_damage(DamageKind::HpLoss, EntityId::Player, EntityId::Player, 4);
+    
+    assert_eq!(result.hp_loss, 4);
+    assert_eq!(game.player().get_block(), 10);
+    assert_eq!(game.player().get_current_health(), 96);
+}
+
+#[test]
+fn test_intangible_caps_every_kind() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(EntityId::Player, StatusType::INTANGIBLE, 1);
+    
+    for kind in [DamageKind::Attack, DamageKind::Thorns, DamageKind::HpLoss, DamageKind::Poison] {
+        assert_eq!(game.calculate_damage(kind, ENEMY, Some(EntityId::Player), 20).damage, 1);
+    }
+}
+
+#[test]
+fn test_thorns_damage_is_blocked_but_not_an_attack() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(ENEMY, StatusType::THORNS, 3);
+    game.add_status(EntityId::Player, StatusType::THORNS, 5);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
+    game.player_mut().set_block(1);
+    
+    game.deal_damage(EntityId::Player, ENEMY, 6);
+    
+    assert_eq!(game.player().get_current_health(), 98);
+    assert_eq!(game.player().get_block(), 0);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), 50 - 8);
+}
+
+#[test]
+fn test_hp_loss_triggers_rupture_but_attacks_do_not() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    game.add_effect(EntityId::Player, Box::new(GainStrengthOnHpLoss));
+    game.add_card_to_hand(strike(100, false));
+    
+    game.deal_damage(ENEMY, EntityId::Player, 5);
+    assert_eq!(game.get_status(EntityId::Player, StatusType::STRENGTH), 0);
+    
+    game.apply(PlayerAction::UseHeroAbility).unwrap();
+    assert_eq!(game.get_status(EntityId::Player, StatusType::STRENGTH), 1);
+}
+
+#[test]
+fn test_retaliation_answers_attacks_only() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_effect(EntityId::Player, Box::new(Retaliate(4)));
+    let enemy_hp = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    
+    game.apply_damage(DamageKind::Poison, ENEMY, EntityId::Player, 3);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), enemy_hp);
+    
+    game.deal_damage(ENEMY, EntityId::Player, 3);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), enemy_hp - 4);
+}
@@ -0,0 +1,133 @@
+use std::rc::Rc;
+use serde::{Serialize, Deserialize};
+
+use SlayTheSpire::core::{DamageContext, DamageKind, DamageModifier, DamageStage, Effect, EffectUIState, EnemyId, EntityId};
+use SlayTheSpire::core::{GameEvent, GameState, StatusType};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::core::registry::load_damage_modifier;
+use SlayTheSpire::save::default_registry;
+
+mod common;
+use common::{create_game, ENEMY};
+
+/// Doubles the base damage of its owner's attacks.
+#[derive(Clone, Debug)]
+struct DoubleBase;
+
+impl Effect for DoubleBase {
+    fn on_event(&mut self, _event: &GameEvent, _owner: EntityId, _game_state: &mut GameState) {}
+    
+    fn modify_damage(&self, owner: EntityId, stage: DamageStage, context: &DamageContext, damage: f32) -> f32 {
+        if stage == DamageStage::Base && context.source == owner { damage * 2.0 } else { damage }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState { name: "Double Base".to_string(), description: String::new(), counters: vec![] }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "double_base"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+}
+
+/// Relic-style cap on every hit the player takes.
+#[derive(Debug, Serialize, Deserialize)]
+struct CapPlayerDamage(f32);
+
+impl DamageModifier for CapPlayerDamage {
+    fn stage(&self) -> DamageStage {
+        DamageStage::Cap
+    }
+    
+    fn modify(&self, context: &DamageContext, _game_state: &GameState, damage: f32) -> f32 {
+        if context.target == Some(EntityId::Player) { damage.min(self.0) } else { damage }
+    }
+    
+    fn id(&self) -> &'static str {
+        "cap_player_damage"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap()
+    }
+}
+
+#[test]
+fn test_additive_stages_run_before_multipliers() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
+    game.add_status(EntityId::Player, StatusType::WEAK, 1);
+    
+    assert_eq!(game.calculate_damage(DamageKind::Attack, EntityId::Player, Some(ENEMY), 6).damage, 6);
+}
+
+#[test]
+fn test_fractions_are_dropped_once_after_all_multipliers() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(EntityId::Player, StatusType::WEAK, 1);
+    game.add_status(ENEMY, StatusType::VULNERABLE, 1);
+    
+    assert_eq!(game.calculate_damage(DamageKind::Attack, EntityId::Player, Some(ENEMY), 9).damage, 10);
+}
+
+#[test]
+fn test_effects_and_standalone_modifiers_join_the_pipeline() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_effect(EntityId::Player, Box::new(DoubleBase));
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
+    assert_eq!(game.calculate_damage(DamageKind::Attack, EntityId::Player, Some(ENEMY), 6).damage, 14);
+    assert_eq!(game.calculate_damage(DamageKind::Attack, ENEMY, Some(EntityId::Player), 6).damage, 6);
+    
+    game.add_damage_modifier(Rc::new(CapPlayerDamage(4.0)));
+    let hp_before = game.player().get_current_health();
+    game.deal_damage(ENEMY, EntityId::Player, 20);
+    assert_eq!(game.player().get_current_health(), hp_before - 4);
+}
+
+#[test]
+fn test_preview_matches_the_damage_actually_dealt() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 3);
+    game.add_status(ENEMY, StatusType::VULNERABLE, 2);
+    game.gain_block(ENEMY, 5);
+    
+    let preview = game.calculate_damage(DamageKind::Attack, EntityId::Player, Some(ENEMY), 6);
+    assert_eq!((preview.damage, preview.blocked, preview.hp_loss), (13, 5, 8));
+    
+    let hp_before = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    game.deal_damage(EntityId::Player, ENEMY, 6);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), hp_before - preview.hp_loss);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 0);
+}
+
+#[test]
+fn test_untargeted_preview_ignores_defender_modifiers() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 1);
+    game.add_status(ENEMY, StatusType::VULNERABLE, 1);
+    
+    assert_eq!(game.calculate_damage(DamageKind::Attack, EntityId::Player, None, 6).damage, 7);
+    assert_eq!(game.calculate_damage(DamageKind::Attack, EntityId::Player, Some(ENEMY), 6).damage, 10);
+}
+
+#[test]
+fn test_standalone_modifiers_survive_a_save() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_damage_modifier(Rc::new(CapPlayerDamage(4.0)));
+    let mut registry = default_registry();
+    registry.register_damage_modifier("cap_player_damage", load_damage_modifier::<CapPlayerDamage>);
+    
+    let mut restored = GameState::from_snapshot(game.snapshot(), &registry).unwrap();
+    restored.deal_damage(ENEMY, EntityId::Player, 20);
+    
+    assert_eq!(restored.player().get_current_health(), game.player().get_current_health() - 4);
+    assert!(GameState::from_snapshot(game.snapshot(), &default_registry()).is_err());
+}
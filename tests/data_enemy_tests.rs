@@ -0,0 +1,219 @@
+use SlayTheSpire::core::{CombatView, CombatantView, Enemy, EnemyId, EntityId, GameRng, GameState, Intent, IntentKind, Player, STSClass, StatusType};
+use SlayTheSpire::core::base_state::{Modifier, State};
+use SlayTheSpire::enemies::{DataEnemy, EnemyDefinition};
+use SlayTheSpire::save::default_registry;
+
+const MOVES: &str = r#"
+    "moves": {
+        "slash": { "kind": "attack", "description": "Attack for 5", "actions": [{ "type": "damage", "amount": 5 }] },
+        "guard": { "kind": "defend", "description": "Gain 5 Block", "actions": [{ "type": "block", "amount": 5 }] },
+        "roar": { "kind": "buff", "description": "Gain 2 Strength", "actions": [{ "type": "status", "status": "Strength", "stacks": 2, "target": "self" }] },
+        "flurry": { "kind": "attack", "description": "Attack for 2x3", "actions": [{ "type": "damage", "amount": 2, "hits": 3 }] }
+    }
+"#;
+
+fn definition(ai: &str) -> EnemyDefinition {
+    let json = format!(r#"{{ "name": "Tester", "max_health": 40, {}, "ai": {} }}"#, MOVES, ai);
+    EnemyDefinition::from_json("tester", &json).unwrap()
+}
+
+fn combatant(health: i32, block: i32) -> CombatantView {
+    CombatantView { health, max_health: 40, block, statuses: Vec::new() }
+}
+
+fn quiet_view() -> CombatView {
+    CombatView {
+        turn: 0,
+        viewer: EnemyId(0),
+        player: combatant(40, 0),
+        enemies: vec![(EnemyId(0), combatant(40, 0))],
+    }
+}
+
+fn moves_picked_with(enemy: &mut DataEnemy, view: &CombatView, turns: usize) -> Vec<String> {
+    let mut rng = GameRng::new(5);
+    (0..turns)
+        .map(|_| enemy.choose_intent(view, &mut rng).move_name().unwrap().to_string())
+        .collect()
+}
+
+fn moves_picked(enemy: &mut DataEnemy, turns: usize) -> Vec<String> {
+    moves_picked_with(enemy, &quiet_view(), turns)
+}
+
+#[test]
+fn test_opening_then_fixed_cycle() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "opening": ["roar"], "pattern": { "type": "cycle", "moves": ["slash", "guard"] } }"#,
+    ));
+    
+    assert_eq!(moves_picked(&mut enemy, 5), vec!["roar", "slash", "guard", "slash", "guard"]);
+    assert_eq!(enemy.move_history().len(), 5);
+}
+
+#[test]
+fn test_weighted_choice_never_exceeds_max_in_a_row() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "pattern": { "type": "weighted", "moves": [
+            { "move": "slash", "weight": 95, "max_in_a_row": 2 },
+            { "move": "guard", "weight": 5 }
+        ] } }"#,
+    ));
+    
+    let picked = moves_picked(&mut enemy, 60);
+    assert!(picked.windows(3).all(|w| !(w[0] == "slash" && w[1] == "slash" && w[2] == "slash")));
+    assert!(picked.iter().filter(|m| *m == "slash").count() > 30);
+}
+
+#[test]
+fn test_conditional_move_fires_once_below_threshold() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "conditional": [{ "when": { "health_below_percent": 50 }, "move": "roar", "once": true }],
+             "pattern": { "type": "cycle", "moves": ["slash"] } }"#,
+    ));
+    
+    assert_eq!(moves_picked(&mut enemy, 1), vec!["slash"]);
+    enemy.set_health(19);
+    assert_eq!(moves_picked(&mut enemy, 3), vec!["roar", "slash", "slash"]);
+}
+
+#[test]
+fn test_moves_build_intents_from_actions() {
+    let enemy = DataEnemy::from_definition(definition(
+        r#"{ "pattern": { "type": "cycle", "moves": ["flurry", "roar"] } }"#,
+    ));
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let mut game = GameState::new_with_rng(player, vec![Box::new(enemy) as Box<dyn Enemy>], Vec::new(), GameRng::new(1));
+    game.start_combat();
+    
+    let preview = game.enemy_intent_preview(EnemyId(0)).unwrap();
+    assert_eq!((preview.kind, preview.damage, preview.hits), (IntentKind::Attack, Some(2), 3));
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(game.player().get_current_health(), 94);
+    
+    game.start_player_turn();
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().kind(), IntentKind::Buff);
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(game.get_status(EntityId::Enemy(EnemyId(0)), StatusType::STRENGTH), 2);
+}
+
+#[test]
+fn test_saved_enemy_keeps_its_decided_move_and_history() {
+    let registry = default_registry();
+    let mut enemy = registry.create_enemy("dragonling").unwrap();
+    let intent = enemy.choose_intent(&quiet_view(), &mut GameRng::new(1));
+    enemy.set_intent(Some(intent));
+    
+    let mut restored = registry.load_enemy("dragonling", &enemy.save_state()).unwrap();
+    
+    assert_eq!(restored.intent().and_then(|intent| intent.move_name()), Some("bite"));
+    assert_eq!(restored.choose_intent(&quiet_view(), &mut GameRng::new(1)).move_name(), Some("guard"));
+    assert_eq!(restored.move_history(), ["bite", "guard"]);
+}
+
+#[test]
+fn test_forced_move_replaces_the_decided_one_in_the_history() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "conditional": [{ "when": { "last_move": "slash" }, "move": "guard" }],
+             "pattern": { "type": "cycle", "moves": ["slash"] } }"#,
+    ));
+    let intent = enemy.choose_intent(&quiet_view(), &mut GameRng::new(1));
+    enemy.set_intent(Some(intent));
+    
+    enemy.override_intent(Intent::stunned());
+    enemy.set_intent(None);
+    
+    assert_eq!(enemy.move_history(), ["stunned"]);
+    assert_eq!(moves_picked(&mut enemy, 2), ["slash", "guard"]);
+}
+
+#[test]
+fn test_stunned_enemy_resumes_its_cycle_at_the_skipped_move() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "pattern": { "type": "cycle", "moves": ["slash", "guard", "roar"] } }"#,
+    ));
+    assert_eq!(moves_picked(&mut enemy, 1), ["slash"]);
+    
+    let intent = enemy.choose_intent(&quiet_view(), &mut GameRng::new(1));
+    assert_eq!(intent.move_name(), Some("guard"));
+    enemy.set_intent(Some(intent));
+    enemy.override_intent(Intent::stunned());
+    enemy.set_intent(None);
+    
+    assert_eq!(moves_picked(&mut enemy, 2), ["guard", "roar"]);
+    assert_eq!(enemy.move_history(), ["slash", "stunned", "guard", "roar"]);
+}
+
+#[test]
+fn test_unknown_moves_are_rejected() {
+    let json = format!(r#"{{ "name": "Tester", "max_health": 40, {}, "ai": {{ "pattern": {{ "type": "cycle", "moves": ["fly"] }} }} }}"#, MOVES);
+    
+    assert!(EnemyDefinition::from_json("tester", &json).is_err());
+}
+
+#[test]
+fn test_enemy_buffs_when_player_has_high_block() {
+    let enemy = DataEnemy::from_definition(definition(
+        r#"{ "conditional": [{ "when": { "player_block_at_least": 10 }, "move": "roar" }],
+             "pattern": { "type": "cycle", "moves": ["slash"] } }"#,
+    ));
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let mut game = GameState::new_with_rng(player, vec![Box::new(enemy) as Box<dyn Enemy>], Vec::new(), GameRng::new(1));
+    game.player_mut().add_modifier(Modifier::RetainBlock);
+    game.start_combat();
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().move_name(), Some("slash"));
+    
+    game.gain_block(EntityId::Player, 20);
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    game.start_player_turn();
+    
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().move_name(), Some("roar"));
+}
+
+#[test]
+fn test_enemy_sees_allies_through_the_view() {
+    let mut enemy = DataEnemy::from_definition(definition(
+        r#"{ "conditional": [{ "when": { "ally_health_below_percent": 30 }, "move": "guard" }],
+             "pattern": { "type": "cycle", "moves": ["slash"] } }"#,
+    ));
+    let mut view = quiet_view();
+    view.enemies.push((EnemyId(1), combatant(20, 0)));
+    assert_eq!(moves_picked_with(&mut enemy, &view, 1), vec!["slash"]);
+    
+    view.enemies[1].1.health = 10;
+    assert_eq!(moves_picked_with(&mut enemy, &view, 1), vec!["guard"]);
+}
+
+#[test]
+fn test_phases_track_sleep_until_damaged_or_rested() {
+    let sleeper = r#"{
+        "initial_phase": "asleep",
+        "conditional": [
+            { "when": { "all": [{ "phase": "asleep" }, { "any": [{ "damaged": null }, { "turns_in_phase_at_least": 2 }] }] }, "move": "wake" },
+            { "when": { "phase": "asleep" }, "move": "sleep" }
+        ],
+        "pattern": { "type": "cycle", "moves": ["slash"] }
+    }"#;
+    let json = format!(
+        r#"{{ "name": "Sleeper", "max_health": 40, "moves": {{
+            "sleep": {{ "kind": "unknown", "description": "Sleeping", "actions": [] }},
+            "wake": {{ "kind": "unknown", "description": "Waking", "actions": [], "next_phase": "awake" }},
+            "slash": {{ "kind": "attack", "description": "Attack for 5", "actions": [{{ "type": "damage", "amount": 5 }}] }}
+        }}, "ai": {} }}"#,
+        sleeper,
+    );
+    let definition = EnemyDefinition::from_json("sleeper", &json).unwrap();
+    
+    let mut rested = DataEnemy::from_definition(definition.clone());
+    assert_eq!(moves_picked(&mut rested, 4), vec!["sleep", "sleep", "wake", "slash"]);
+    assert_eq!(rested.ai_state().phase.as_deref(), Some("awake"));
+    
+    let mut poked = DataEnemy::from_definition(definition);
+    assert_eq!(moves_picked(&mut poked, 1), vec!["sleep"]);
+    poked.set_health(35);
+    assert_eq!(moves_picked(&mut poked, 2), vec!["wake", "slash"]);
+}
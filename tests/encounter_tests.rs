@@ -0,0 +1,150 @@
+use SlayTheSpire::core::{GameRng, GameState, Player, STSClass};
+use SlayTheSpire::core::game_state::MAX_ENEMIES;
+use SlayTheSpire::encounters::{ActEncounters, EncounterDefinition, EncounterHistory, EncounterTier};
+use SlayTheSpire::save::{default_registry, RunSave};
+
+const TIERS: [EncounterTier; 4] = [EncounterTier::Weak, EncounterTier::Strong, EncounterTier::Elite, EncounterTier::Boss];
+
+fn three_way_act() -> ActEncounters {
+    ActEncounters::from_json(r#"{
+        "act": 1,
+        "weak_fights": 2,
+        "weak": [{ "encounter": "a", "weight": 1 }, { "encounter": "b", "weight": 1 }, { "encounter": "c", "weight": 5 }],
+        "strong": [{ "encounter": "d", "weight": 1 }],
+        "elite": [{ "encounter": "e", "weight": 1 }],
+        "boss": [{ "encounter": "f", "weight": 1 }]
+    }"#).unwrap()
+}
+
+fn picks(act: &ActEncounters, tier: EncounterTier, seed: u64, count: usize) -> Vec<String> {
+    let mut history = EncounterHistory::default();
+    let mut rng = GameRng::new(seed);
+    (0..count)
+        .map(|_| {
+            let id = act.pick(tier, &history, &mut rng).to_string();
+            history.record(tier, &id);
+            id
+        })
+        .collect()
+}
+
+#[test]
+fn test_every_act_one_encounter_can_be_built() {
+    let registry = default_registry();
+    let act = ActEncounters::load(1).unwrap();
+    
+    for tier in TIERS {
+        for entry in act.pool(tier) {
+            let encounter = EncounterDefinition::load(&entry.encounter).unwrap();
+            assert_eq!(encounter.create_enemies(&registry).unwrap().len(), encounter.enemies.len());
+            assert!(encounter.slots().iter().all(|slot| *slot < MAX_ENEMIES), "{}", encounter.id);
+        }
+    }
+}
+
+#[test]
+fn test_encounter_places_enemies_and_overrides_health() {
+    let encounter = EncounterDefinition::load("three_louses").unwrap();
+    let enemies = encounter.create_enemies(&default_registry()).unwrap();
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    
+    let game = GameState::new_with_rng(player, enemies, Vec::new(), GameRng::new(1)).with_enemy_slots(&encounter.slots()).unwrap();
+    
+    let placed: Vec<(usize, i32)> = game.enemies()
+        .iter()
+        .map(|(id, enemy)| (game.enemy_slot(*id).unwrap(), enemy.get_current_health()))
+        .collect();
+    assert_eq!(placed, [(1, 10), (2, 15), (3, 14)]);
+    assert_eq!(game.enemies()[1].1.get_max_health(), 15);
+}
+
+#[test]
+fn test_encounters_reject_shared_slots() {
+    let json = r#"{ "name": "Crowd", "enemies": [{ "id": "cultist", "slot": 1 }, { "id": "cultist", "slot": 1 }] }"#;
+    
+    assert!(EncounterDefinition::from_json("crowd", json).is_err());
+}
+
+#[test]
+fn test_encounters_stay_within_the_roster_limit() {
+    let crowd = format!(r#"{{ "name": "Crowd", "enemies": [{}] }}"#, [r#"{ "id": "cultist" }"#; MAX_ENEMIES + 1].join(", "));
+    let off_field = format!(r#"{{ "name": "Far", "enemies": [{{ "id": "cultist", "slot": {} }}] }}"#, MAX_ENEMIES);
+    
+    assert!(EncounterDefinition::from_json("crowd", &crowd).is_err());
+    assert!(EncounterDefinition::from_json("far", &off_field).is_err());
+}
+
+#[test]
+fn test_enemy_slots_must_match_the_roster() {
+    let game = || {
+        let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+        let enemies = ["cultist", "cultist"].iter().map(|id| default_registry().create_enemy(id).unwrap()).collect();
+        GameState::new_with_rng(player, enemies, Vec::new(), GameRng::new(1))
+    };
+    
+    assert!(game().with_enemy_slots(&[1]).is_err());
+    assert!(game().with_enemy_slots(&[1, 2, 3]).is_err());
+    assert!(game().with_enemy_slots(&[2, 2]).is_err());
+    assert!(game().with_enemy_slots(&[0, MAX_ENEMIES]).is_err());
+    assert!(game().with_enemy_slots(&[3, 1]).is_ok());
+}
+
+#[test]
+fn test_hallway_fights_turn_strong_after_the_weak_ones() {
+    let act = three_way_act();
+    let mut history = EncounterHistory::default();
+    
+    let mut tiers = Vec::new();
+    for _ in 0..3 {
+        let tier = act.hallway_tier(&history);
+        history.record(tier, act.pick(tier, &history, &mut GameRng::new(1)));
+        history.record(EncounterTier::Elite, "e");
+        tiers.push(tier);
+    }
+    
+    assert_eq!(tiers, [EncounterTier::Weak, EncounterTier::Weak, EncounterTier::Strong]);
+}
+
+#[test]
+fn test_picks_never_repeat_the_last_two_encounters() {
+    let picked = picks(&three_way_act(), EncounterTier::Weak, 9, 30);
+    
+    assert!(picked.windows(3).all(|w| w[2] != w[0] && w[2] != w[1]));
+}
+
+#[test]
+fn test_picks_fall_back_to_repeats_when_the_pool_runs_dry() {
+    assert_eq!(picks(&three_way_act(), EncounterTier::Strong, 9, 3), ["d", "d", "d"]);
+}
+
+#[test]
+fn test_failed_encounter_is_not_recorded() {
+    let mut history = EncounterHistory::default();
+    let mut rng = GameRng::new(5);
+    
+    assert!(three_way_act().next_encounter(EncounterTier::Strong, &mut history, &mut rng).is_err());
+    
+    assert_eq!(history, EncounterHistory::default());
+    assert_eq!(serde_json::to_value(&rng).unwrap(), serde_json::to_value(GameRng::new(5)).unwrap());
+}
+
+#[test]
+fn test_picks_are_seeded() {
+    let act = ActEncounters::load(1).unwrap();
+    
+    assert_eq!(picks(&act, EncounterTier::Strong, 4, 10), picks(&act, EncounterTier::Strong, 4, 10));
+}
+
+#[test]
+fn test_run_save_keeps_encounter_history() {
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let mut history = EncounterHistory::default();
+    history.record(EncounterTier::Weak, "cultist");
+    
+    let json = RunSave::new(&player, &[], 1, 0, &GameRng::new(1)).with_encounters(&history).to_json().unwrap();
+    assert_eq!(RunSave::from_json(&json).unwrap().encounters, history);
+    
+    let mut older: serde_json::Value = serde_json::from_str(&json).unwrap();
+    older.as_object_mut().unwrap().remove("encounters");
+    assert_eq!(RunSave::from_json(&older.to_string()).unwrap().encounters, EncounterHistory::default());
+}
@@ -0,0 +1,87 @@
+use SlayTheSpire::cards::{strike, defend, Ritual};
+use SlayTheSpire::core::{EnemyId, EntityId, GameState};
+use SlayTheSpire::core::base_state::{State, StatusType};
+use SlayTheSpire::enemies::{CurlUp, Enrage};
+
+mod common;
+use common::create_game;
+
+const FIRST: EntityId = EntityId::Enemy(EnemyId(0));
+
+fn strike_first(game: &mut GameState) {
+    game.add_card_to_hand(strike(100, false));
+    let index = game.hand().len() - 1;
+    game.play_card(index, Some(FIRST)).unwrap();
+}
+
+#[test]
+fn test_ritual_grows_an_enemy_from_its_second_turn() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_effect(FIRST, Box::new(Ritual { amount: 3, skip_first: true }));
+    
+    game.execute_all_enemy_turns();
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::STRENGTH), 0);
+    
+    game.execute_all_enemy_turns();
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::STRENGTH), 3);
+    assert_eq!(game.enemy(EnemyId(1)).unwrap().get_status(&StatusType::STRENGTH), 0);
+    assert_eq!(game.player().get_status(&StatusType::STRENGTH), 0);
+}
+
+#[test]
+fn test_curl_up_blocks_once_after_the_first_hit() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_effect(FIRST, Box::new(CurlUp::new(5)));
+    
+    strike_first(&mut game);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 5);
+    assert_eq!(game.effects().count(), 0);
+    
+    strike_first(&mut game);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 0);
+}
+
+#[test]
+fn test_enrage_reacts_to_skills_only() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_effect(FIRST, Box::new(Enrage { amount: 2 }));
+    
+    game.add_card_to_hand(defend(100, false));
+    game.play_card(game.hand().len() - 1, Some(EntityId::Player)).unwrap();
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::STRENGTH), 2);
+    
+    strike_first(&mut game);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::STRENGTH), 2);
+}
+
+#[test]
+fn test_thorns_hurts_the_attacker_through_block() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_status(FIRST, StatusType::THORNS, 3);
+    game.gain_block(EntityId::Player, 1);
+    let hp_before = game.player().get_current_health();
+    
+    strike_first(&mut game);
+    
+    assert_eq!(game.player().get_current_health(), hp_before - 2);
+    assert_eq!(game.player().get_block(), 0);
+}
+
+#[test]
+fn test_powers_are_removed_when_their_owner_dies() {
+    let mut game = create_game(&["dragonling", "dragonling"], 1);
+    game.start_player_turn();
+    game.add_effect(FIRST, Box::new(CurlUp::new(3)));
+    game.add_effect(EntityId::Enemy(EnemyId(1)), Box::new(Enrage { amount: 2 }));
+    game.enemy_mut(EnemyId(0)).unwrap().set_health(1);
+    
+    strike_first(&mut game);
+    
+    assert!(game.enemy(EnemyId(0)).is_none());
+    let owners: Vec<EntityId> = game.effects().map(|(owner, _)| owner).collect();
+    assert_eq!(owners, vec![EntityId::Enemy(EnemyId(1))]);
+}
@@ -0,0 +1,117 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use SlayTheSpire::core::{Effect, EffectUIState, EntityId, GameEvent, GameState};
+use SlayTheSpire::core::base_state::StatusType;
+
+mod common;
+use common::create_game;
+
+type Trace = Rc<RefCell<Vec<String>>>;
+
+/// Writes its name to a shared trace for every event it sees, and optionally
+/// reacts to turn starts by adding another effect or applying a status.
+#[derive(Clone, Debug)]
+struct Probe {
+    name: &'static str,
+    priority: i32,
+    trace: Trace,
+    spawn_on_turn_start: Option<Box<Probe>>,
+    strength_on_turn_start: bool,
+}
+
+impl Probe {
+    fn new(name: &'static str, priority: i32, trace: &Trace) -> Self {
+        Probe { name, priority, trace: trace.clone(), spawn_on_turn_start: None, strength_on_turn_start: false }
+    }
+}
+
+impl Effect for Probe {
+    fn on_event(&mut self, event: &GameEvent, owner: EntityId, game_state: &mut GameState) {
+        self.trace.borrow_mut().push(format!("{}:{:?}", self.name, event));
+        
+        if let GameEvent::TurnStarted { .. } = event {
+            if let Some(probe) = self.spawn_on_turn_start.take() {
+                game_state.add_effect(owner, probe);
+            }
+            if self.strength_on_turn_start {
+                self.strength_on_turn_start = false;
+                game_state.add_status(owner, StatusType::STRENGTH, 1);
+            }
+        }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState { name: self.name.to_string(), description: String::new(), counters: vec![] }
+    }
+    
+    fn priority(&self) -> i32 {
+        self.priority
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "probe"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+}
+
+fn seen_by(trace: &Trace, name: &str) -> Vec<String> {
+    trace.borrow().iter().filter(|line| line.starts_with(name)).cloned().collect()
+}
+
+#[test]
+fn test_handlers_run_in_priority_order() {
+    let trace = Trace::default();
+    let mut game = create_game(&["dragonling"], 1);
+    game.add_effect(EntityId::Player, Box::new(Probe::new("low", -5, &trace)));
+    game.add_effect(EntityId::Player, Box::new(Probe::new("first", 0, &trace)));
+    game.add_effect(EntityId::Player, Box::new(Probe::new("high", 10, &trace)));
+    game.add_effect(EntityId::Player, Box::new(Probe::new("second", 0, &trace)));
+    
+    game.fire_event(GameEvent::CombatStarted);
+    
+    let names: Vec<String> = trace.borrow().iter().map(|line| line.split(':').next().unwrap().to_string()).collect();
+    assert_eq!(names, vec!["high", "first", "second", "low"]);
+}
+
+#[test]
+fn test_effect_added_mid_dispatch_is_kept() {
+    let trace = Trace::default();
+    let mut game = create_game(&["dragonling"], 1);
+    let mut parent = Probe::new("parent", 0, &trace);
+    parent.spawn_on_turn_start = Some(Box::new(Probe::new("child", 0, &trace)));
+    game.add_effect(EntityId::Player, Box::new(parent));
+    
+    game.start_player_turn();
+    
+    assert_eq!(game.effects().count(), 2);
+    assert!(seen_by(&trace, "child").is_empty());
+    
+    game.end_player_turn();
+    assert!(seen_by(&trace, "child").contains(&"child:TurnEnded { entity: Player }".to_string()));
+}
+
+#[test]
+fn test_nested_events_reach_every_handler_after_the_outer_event() {
+    let trace = Trace::default();
+    let mut game = create_game(&["dragonling"], 1);
+    let mut trigger = Probe::new("trigger", 0, &trace);
+    trigger.strength_on_turn_start = true;
+    game.add_effect(EntityId::Player, Box::new(trigger));
+    game.add_effect(EntityId::Player, Box::new(Probe::new("watcher", 0, &trace)));
+    
+    game.start_player_turn();
+    
+    let watcher = seen_by(&trace, "watcher");
+    let turn_started = watcher.iter().position(|line| line.contains("TurnStarted")).unwrap();
+    let strength = watcher.iter().position(|line| line.contains("StatusApplied")).unwrap();
+    assert!(turn_started < strength, "nested event should be queued behind the outer one");
+    assert!(seen_by(&trace, "trigger").iter().any(|line| line.contains("StatusApplied")));
+}
@@ -0,0 +1,119 @@
+use SlayTheSpire::core::{CardPile, DamageKind, EnemyId, EntityId, GameEvent, GameState, LogRecord, PlayerAction};
+use SlayTheSpire::core::base_state::{Modifier, State, StatusType};
+use SlayTheSpire::core::card::Card;
+use SlayTheSpire::cards::{defend, strike};
+
+mod common;
+use common::create_game_with_deck;
+
+fn defends() -> Vec<Card> {
+    (1..=10).map(|i| defend(i, false)).collect()
+}
+
+fn events(game: &GameState) -> Vec<GameEvent> {
+    game.recording()
+        .unwrap()
+        .log
+        .entries()
+        .iter()
+        .filter_map(|entry| match &entry.record {
+            LogRecord::Event(event) => Some(event.clone()),
+            LogRecord::Input(_) => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_combat_start_draws_and_starts_turn() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    
+    let events = events(&game);
+    assert_eq!(events[0], GameEvent::CombatStarted);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::CardDrawn { .. })).count(), 5);
+    assert_eq!(events.last(), Some(&GameEvent::TurnStarted { entity: EntityId::Player }));
+}
+
+#[test]
+fn test_lethal_strike_fires_death_and_combat_end() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    game.enemy_mut(EnemyId(0)).unwrap().set_health(3);
+    game.add_card_to_hand(strike(99, false));
+    
+    let target = EntityId::Enemy(EnemyId(0));
+    game.apply(PlayerAction::PlayCard { hand_index: 5, target: Some(target) }).unwrap();
+    
+    let events = events(&game);
+    assert!(events.contains(&GameEvent::CardCreated { card: 99, pile: CardPile::Hand }));
+    assert!(events.contains(&GameEvent::EnergySpent { amount: 1 }));
+    assert!(events.contains(&GameEvent::HpLost { entity: target, amount: 3 }));
+    assert!(events.contains(&GameEvent::DamageDealt { source: EntityId::Player, target, amount: 6, kind: DamageKind::Attack }));
+    assert!(events.contains(&GameEvent::EnemyDied { enemy: target }));
+    assert_eq!(events.last(), Some(&GameEvent::CombatEnded { victory: true }));
+}
+
+#[test]
+fn test_poison_loses_hp_and_removes_status() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.add_status(EntityId::Player, StatusType::POISON, 1);
+    game.start_combat();
+    
+    let events = events(&game);
+    assert!(events.contains(&GameEvent::StatusApplied { entity: EntityId::Player, status: StatusType::POISON, stacks: 1 }));
+    assert!(events.contains(&GameEvent::HpLost { entity: EntityId::Player, amount: 1 }));
+    assert!(events.contains(&GameEvent::StatusRemoved { entity: EntityId::Player, status: StatusType::POISON }));
+    assert!(events.contains(&GameEvent::DamageDealt { source: EntityId::Player, target: EntityId::Player, amount: 1, kind: DamageKind::Poison }));
+}
+
+#[test]
+fn test_end_turn_discards_or_retains_hand() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    game.end_player_turn();
+    assert_eq!(events(&game).iter().filter(|e| matches!(e, GameEvent::CardDiscarded { .. })).count(), 5);
+    
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    game.player_mut().add_modifier(Modifier::RetainHand);
+    game.end_player_turn();
+    let events = events(&game);
+    assert_eq!(events.iter().filter(|e| matches!(e, GameEvent::CardRetained { .. })).count(), 5);
+    assert!(!events.iter().any(|e| matches!(e, GameEvent::CardDiscarded { .. })));
+}
+
+#[test]
+fn test_hero_ability_events() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    let exhausted = game.hand().last().unwrap().instance_id();
+    
+    game.apply(PlayerAction::UseHeroAbility).unwrap();
+    game.apply(PlayerAction::EndTurn).unwrap();
+    
+    let events = events(&game);
+    assert!(events.contains(&GameEvent::EnergySpent { amount: 1 }));
+    assert!(events.contains(&GameEvent::CardExhausted { card: exhausted }));
+    assert!(events.contains(&GameEvent::HpLost { entity: EntityId::Player, amount: 2 }));
+    assert!(events.contains(&GameEvent::DamageDealt { source: EntityId::Player, target: EntityId::Player, amount: 2, kind: DamageKind::HpLoss }));
+    assert!(events.contains(&GameEvent::CardCreated { card: exhausted, pile: CardPile::Hand }));
+}
+
+#[test]
+fn test_reshuffle_fires_event() {
+    let mut game = create_game_with_deck(&["dragonling"], defends(), 9);
+    game.start_recording();
+    game.start_combat();
+    game.apply(PlayerAction::EndTurn).unwrap();
+    
+    // Ten cards, five drawn per turn: the third turn reshuffles the discard pile.
+    game.apply(PlayerAction::EndTurn).unwrap();
+    
+    assert!(events(&game).contains(&GameEvent::DrawPileShuffled));
+}
@@ -1,5 +1,5 @@
-use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, State, StatusType, Enemy};
-use SlayTheSpire::enemies::Dragonling;
+use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, EnemyId, State, StatusType, Enemy};
+use SlayTheSpire::enemies::DataEnemy;
 use SlayTheSpire::cards::{strike, defend, inflame, upgrade_card};
 
 #[test]
@@ -14,8 +14,8 @@ fn test_complete_combat_scenario() {
     }
     
     let enemies = vec![
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
-        Box::new(Dragonling::new()) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
     ];
     
     let mut game = GameState::new_with_deck(player, enemies, starting_deck);
@@ -29,8 +29,8 @@ fn test_complete_combat_scenario() {
     assert_eq!(game.player().get_energy(), 3);
     
     if let Some(strike_idx) = game.hand().iter().position(|c| c.id() == "strike") {
-        game.play_card(strike_idx, Some(EntityId::Enemy(0))).unwrap();
-        assert!(game.enemies()[0].get_current_health() < 50);
+        game.play_card(strike_idx, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
+        assert!(game.enemy(EnemyId(0)).unwrap().get_current_health() < 50);
     }
     
     game.end_player_turn();
@@ -47,12 +47,12 @@ fn test_complete_combat_scenario() {
 #[test]
 fn test_victory_condition() {
     let player = Player::new(STSClass::Ironclad, "Hero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     assert!(!game.is_combat_over());
     
-    game.deal_damage(EntityId::Player, EntityId::Enemy(0), 50);
+    game.deal_damage(EntityId::Player, EntityId::Enemy(EnemyId(0)), 50);
     
     assert!(game.is_combat_over());
     assert!(game.are_all_enemies_dead());
@@ -62,12 +62,12 @@ fn test_victory_condition() {
 #[test]
 fn test_defeat_condition() {
     let player = Player::new(STSClass::Ironclad, "Hero".to_string(), 10);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     assert!(!game.is_combat_over());
     
-    game.deal_damage(EntityId::Enemy(0), EntityId::Player, 20);
+    game.deal_damage(EntityId::Enemy(EnemyId(0)), EntityId::Player, 20);
     
     assert!(game.is_combat_over());
     assert!(!game.are_all_enemies_dead());
@@ -77,7 +77,7 @@ fn test_defeat_condition() {
 #[test]
 fn test_upgrade_system_in_combat() {
     let player = Player::new(STSClass::Ironclad, "Hero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
@@ -93,14 +93,14 @@ fn test_upgrade_system_in_combat() {
     let strike_upgraded2 = strike(102, true);
     game.add_card_to_hand(strike_upgraded2);
     
-    let enemy_hp = game.enemies()[0].get_current_health();
+    let enemy_hp = game.enemy(EnemyId(0)).unwrap().get_current_health();
     
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
-    let damage1 = enemy_hp - game.enemies()[0].get_current_health();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
+    let damage1 = enemy_hp - game.enemy(EnemyId(0)).unwrap().get_current_health();
     
-    let enemy_hp2 = game.enemies()[0].get_current_health();
-    game.play_card(0, Some(EntityId::Enemy(0))).unwrap();
-    let damage2 = enemy_hp2 - game.enemies()[0].get_current_health();
+    let enemy_hp2 = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    game.play_card(0, Some(EntityId::Enemy(EnemyId(0)))).unwrap();
+    let damage2 = enemy_hp2 - game.enemy(EnemyId(0)).unwrap().get_current_health();
     
     assert!(damage2 > damage1);
 }
@@ -108,19 +108,19 @@ fn test_upgrade_system_in_combat() {
 #[test]
 fn test_multi_turn_status_effects() {
     let player = Player::new(STSClass::Ironclad, "Hero".to_string(), 100);
-    let enemies = vec![Box::new(Dragonling::new()) as Box<dyn Enemy>];
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
     let mut game = GameState::new(player, enemies);
     
     game.start_player_turn();
-    game.add_status(EntityId::Player, StatusType::Strength, 2);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
     
-    assert_eq!(game.player().get_status(&StatusType::Strength), 2);
+    assert_eq!(game.player().get_status(&StatusType::STRENGTH), 2);
     
-    game.add_status(EntityId::Enemy(0), StatusType::Vulnerable, 1);
-    assert_eq!(game.enemies()[0].get_status(&StatusType::Vulnerable), 1);
+    game.add_status(EntityId::Enemy(EnemyId(0)), StatusType::VULNERABLE, 1);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::VULNERABLE), 1);
     
     game.end_player_turn();
     game.execute_all_enemy_turns();
     
-    assert_eq!(game.player().get_status(&StatusType::Strength), 2);
+    assert_eq!(game.player().get_status(&StatusType::STRENGTH), 2);
 }
@@ -0,0 +1,128 @@
+use SlayTheSpire::core::{DamageKind, Effect, EffectUIState, EntityId};
+use SlayTheSpire::core::{GameEvent, GameState, LogRecord, Player};
+use SlayTheSpire::core::base_state::State;
+
+mod common;
+use common::{create_game_with_player, player, ENEMY};
+
+fn hurt_player(current_health: i32) -> Player {
+    let mut player = player(100);
+    player.set_health(current_health);
+    player
+}
+
+fn events(game: &GameState) -> Vec<GameEvent> {
+    game.recording()
+        .unwrap()
+        .log
+        .entries()
+        .iter()
+        .filter_map(|entry| match &entry.record {
+            LogRecord::Event(event) => Some(event.clone()),
+            LogRecord::Input(_) => None,
+        })
+        .collect()
+}
+
+/// Halves every heal its owner receives.
+#[derive(Clone, Debug)]
+struct HalveHealing;
+
+impl Effect for HalveHealing {
+    fn on_event(&mut self, _event: &GameEvent, _owner: EntityId, _game_state: &mut GameState) {}
+    
+    fn modify_heal(&self, owner: EntityId, target: EntityId, amount: i32) -> i32 {
+        if owner == target { amount / 2 } else { amount }
+    }
+    
+    fn ui_state(&self) -> EffectUIState {
+        EffectUIState { name: "Halve Healing".to_string(), description: String::new(), counters: vec![] }
+    }
+    
+    fn clone_box(&self) -> Box<dyn Effect> {
+        Box::new(self.clone())
+    }
+    
+    fn id(&self) -> &'static str {
+        "halve_healing"
+    }
+    
+    fn save_state(&self) -> serde_json::Value {
+        serde_json::Value::Null
+    }
+}
+
+#[test]
+fn test_heal_caps_at_max_and_fires_event() {
+    let mut game = create_game_with_player(hurt_player(95), &["dragonling"], Vec::new(), 1);
+    game.start_recording();
+    
+    assert_eq!(game.heal(EntityId::Player, 10), 5);
+    assert_eq!(game.player().get_current_health(), 100);
+    assert!(events(&game).contains(&GameEvent::Healed { entity: EntityId::Player, amount: 5 }));
+}
+
+#[test]
+fn test_healing_percent_and_effects_reduce_heals() {
+    let mut player = hurt_player(50);
+    player.set_healing_percent(75);
+    let mut game = create_game_with_player(player, &["dragonling"], Vec::new(), 1);
+    game.start_recording();
+    
+    assert_eq!(game.heal(EntityId::Player, 20), 15);
+    
+    game.add_effect(EntityId::Player, Box::new(HalveHealing));
+    assert_eq!(game.heal(EntityId::Player, 20), 7);
+    assert_eq!(game.player().get_current_health(), 72);
+}
+
+#[test]
+fn test_max_hp_raise_heals_and_lower_clamps() {
+    let mut game = create_game_with_player(hurt_player(90), &["dragonling"], Vec::new(), 1);
+    game.start_recording();
+    game.player_mut().set_healing_percent(50);
+    
+    game.raise_max_hp(EntityId::Player, 7);
+    assert_eq!(game.player().get_max_health(), 107);
+    assert_eq!(game.player().get_current_health(), 97);
+    
+    game.lower_max_hp(EntityId::Player, 20);
+    assert_eq!(game.player().get_max_health(), 87);
+    assert_eq!(game.player().get_current_health(), 87);
+    
+    let events = events(&game);
+    assert!(events.contains(&GameEvent::MaxHpChanged { entity: EntityId::Player, amount: 7 }));
+    assert!(events.contains(&GameEvent::MaxHpChanged { entity: EntityId::Player, amount: -20 }));
+    assert!(events.contains(&GameEvent::HpLost { entity: EntityId::Player, amount: 10 }));
+}
+
+#[test]
+fn test_temp_hp_absorbs_attacks_after_block_but_not_hp_loss() {
+    let mut game = create_game_with_player(hurt_player(100), &["dragonling"], Vec::new(), 1);
+    game.start_recording();
+    game.player_mut().set_block(3);
+    game.gain_temp_hp(EntityId::Player, 5);
+    
+    let result = game.apply_damage(DamageKind::Attack, ENEMY, EntityId::Player, 10);
+    assert_eq!((result.blocked, result.temp_hp_lost, result.hp_loss), (3, 5, 2));
+    assert_eq!(game.get_temp_hp(EntityId::Player), 0);
+    assert_eq!(game.player().get_current_health(), 98);
+    
+    game.gain_temp_hp(EntityId::Player, 5);
+    game.apply_damage(DamageKind::HpLoss, EntityId::Player, EntityId::Player, 2);
+    assert_eq!(game.get_temp_hp(EntityId::Player), 5);
+    assert_eq!(game.player().get_current_health(), 96);
+    assert!(events(&game).contains(&GameEvent::TempHpLost { entity: EntityId::Player, amount: 5 }));
+}
+
+#[test]
+fn test_player_heal_outside_combat_respects_healing_percent() {
+    let mut player = hurt_player(40);
+    player.set_healing_percent(50);
+    
+    assert_eq!(player.heal(30), 15);
+    player.raise_max_health(10);
+    assert_eq!((player.get_current_health(), player.get_max_health()), (65, 110));
+    player.lower_max_health(200);
+    assert_eq!((player.get_current_health(), player.get_max_health()), (1, 1));
+}
@@ -0,0 +1,123 @@
+use SlayTheSpire::cards::card_effects::{BlockEffect, DamageEffect, StunAction};
+use SlayTheSpire::core::{Action, EnemyId, EntityId, GameState, Intent, IntentKind, IntentPreview, StatusType};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::save::default_registry;
+
+mod common;
+use common::{create_game, ENEMY};
+
+#[test]
+fn test_intent_is_decided_at_turn_start() {
+    let mut game = create_game(&["dragonling"], 1);
+    assert!(game.enemy_intent(EnemyId(0)).is_none());
+    
+    game.start_combat();
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().description(), "Attack for 8");
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(game.player().get_current_health(), 92);
+    
+    game.start_player_turn();
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().description(), "Gain 6 Block");
+}
+
+#[test]
+fn test_stored_intent_is_executed_unchanged() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    game.set_enemy_intent(EnemyId(0), Intent::new(vec![Box::new(BlockEffect { amount: 11 })], "Gain 11 Block".to_string()));
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    
+    assert_eq!(game.player().get_current_health(), 100);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 11);
+}
+
+#[test]
+fn test_stun_replaces_the_decided_move() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    
+    game.queue_action_bottom(Box::new(StunAction), EntityId::Player, &[ENEMY], None);
+    game.resolve_actions();
+    assert_eq!(game.enemy_intent(EnemyId(0)).unwrap().description(), "Stunned");
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(game.player().get_current_health(), 100);
+}
+
+#[test]
+fn test_restored_combat_keeps_decided_intents() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    
+    let restored = GameState::from_snapshot(game.snapshot(), &default_registry()).unwrap();
+    
+    assert_eq!(restored.enemy_intent(EnemyId(0)).unwrap().description(), "Attack for 8");
+}
+
+#[test]
+fn test_stun_survives_a_save() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    game.queue_action_bottom(Box::new(StunAction), EntityId::Player, &[ENEMY], None);
+    game.resolve_actions();
+    
+    let mut restored = GameState::from_snapshot(game.snapshot(), &default_registry()).unwrap();
+    assert_eq!(restored.enemy_intent(EnemyId(0)).unwrap().description(), "Stunned");
+    assert_eq!(restored.enemy(EnemyId(0)).unwrap().move_history(), game.enemy(EnemyId(0)).unwrap().move_history());
+    
+    restored.end_player_turn();
+    restored.execute_all_enemy_turns();
+    assert_eq!(restored.player().get_current_health(), 100);
+    assert_eq!(restored.enemy(EnemyId(0)).unwrap().get_block(), 0);
+}
+
+#[test]
+fn test_preview_uses_real_damage_calculation() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    assert_eq!(
+        game.enemy_intent_preview(EnemyId(0)),
+        Some(IntentPreview { kind: IntentKind::Attack, damage: Some(8), hits: 1 }),
+    );
+    
+    game.add_status(ENEMY, StatusType::STRENGTH, 2);
+    game.add_status(EntityId::Player, StatusType::VULNERABLE, 1);
+    assert_eq!(game.enemy_intent_preview(EnemyId(0)).unwrap().damage, Some(15));
+    
+    game.add_status(ENEMY, StatusType::WEAK, 1);
+    assert_eq!(game.enemy_intent_preview(EnemyId(0)).unwrap().damage, Some(11));
+}
+
+#[test]
+fn test_preview_counts_hits_and_reports_kind() {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_combat();
+    
+    let hit = || Box::new(DamageEffect { amount: 3 }) as Box<dyn Action>;
+    game.set_enemy_intent(EnemyId(0), Intent::new(vec![hit(), hit(), hit()], "Attack for 3x3".to_string()));
+    assert_eq!(
+        game.enemy_intent_preview(EnemyId(0)),
+        Some(IntentPreview { kind: IntentKind::Attack, damage: Some(3), hits: 3 }),
+    );
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    game.start_player_turn();
+    assert_eq!(
+        game.enemy_intent_preview(EnemyId(0)),
+        Some(IntentPreview { kind: IntentKind::Attack, damage: Some(8), hits: 1 }),
+    );
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    game.start_player_turn();
+    assert_eq!(
+        game.enemy_intent_preview(EnemyId(0)),
+        Some(IntentPreview { kind: IntentKind::Defend, damage: None, hits: 0 }),
+    );
+}
@@ -0,0 +1,117 @@
+use SlayTheSpire::cards::{dark_shackles, defend, disarm, strike};
+use SlayTheSpire::core::{EnemyId, EntityId, GameState};
+use SlayTheSpire::core::base_state::{State, StatusType};
+use SlayTheSpire::core::card::Card;
+
+mod common;
+use common::{create_game, ENEMY};
+
+fn start_game() -> GameState {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_player_turn();
+    game
+}
+
+fn play_on(game: &mut GameState, card: Card, target: EntityId) {
+    game.add_card_to_hand(card);
+    game.play_card(game.hand().len() - 1, Some(target)).unwrap();
+}
+
+fn enemy_strength(game: &GameState) -> i32 {
+    game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::STRENGTH)
+}
+
+#[test]
+fn test_negative_strength_persists_and_weakens_attacks() {
+    let mut game = start_game();
+    play_on(&mut game, disarm(100, false), ENEMY);
+    assert_eq!(enemy_strength(&game), -2);
+    
+    let hp_before = game.player().get_current_health();
+    game.deal_damage(ENEMY, EntityId::Player, 8);
+    assert_eq!(game.player().get_current_health(), hp_before - 6);
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    assert_eq!(enemy_strength(&game), -2);
+}
+
+#[test]
+fn test_strength_can_cross_zero_in_both_directions() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 1);
+    game.add_status(EntityId::Player, StatusType::STRENGTH, -3);
+    assert_eq!(game.player().get_status(&StatusType::STRENGTH), -2);
+    
+    game.add_status(EntityId::Player, StatusType::STRENGTH, 2);
+    assert!(game.player().get_all_statuses().is_empty());
+}
+
+#[test]
+fn test_damage_and_block_never_go_below_zero() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::STRENGTH, -10);
+    game.add_status(EntityId::Player, StatusType::DEXTERITY, -2);
+    let enemy_hp = game.enemy(EnemyId(0)).unwrap().get_current_health();
+    
+    play_on(&mut game, strike(100, false), ENEMY);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_current_health(), enemy_hp);
+    
+    game.add_card_to_hand(defend(101, false));
+    game.play_card(game.hand().len() - 1, None).unwrap();
+    assert_eq!(game.player().get_block(), 3);
+}
+
+#[test]
+fn test_artifact_negates_strength_loss() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::ARTIFACT, 1);
+    
+    play_on(&mut game, disarm(100, false), ENEMY);
+    assert_eq!(enemy_strength(&game), 0);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::ARTIFACT), 0);
+    
+    game.add_status(ENEMY, StatusType::ARTIFACT, 1);
+    game.add_status(ENEMY, StatusType::STRENGTH, 3);
+    assert_eq!(enemy_strength(&game), 3, "gaining Strength is not negated");
+}
+
+#[test]
+fn test_artifact_lets_debuffs_be_removed() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::WEAK, 2);
+    game.add_status(ENEMY, StatusType::ARTIFACT, 1);
+    
+    game.add_status(ENEMY, StatusType::WEAK, -1);
+    
+    let enemy = game.enemy(EnemyId(0)).unwrap();
+    assert_eq!(enemy.get_status(&StatusType::WEAK), 1);
+    assert_eq!(enemy.get_status(&StatusType::ARTIFACT), 1);
+}
+
+#[test]
+fn test_dark_shackles_returns_strength_after_the_enemy_turn() {
+    let mut game = start_game();
+    play_on(&mut game, dark_shackles(100, false), ENEMY);
+    assert_eq!(enemy_strength(&game), -9);
+    
+    let hp_before = game.player().get_current_health();
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    
+    assert_eq!(game.player().get_current_health(), hp_before, "an 8 damage attack at -9 Strength does nothing");
+    assert_eq!(enemy_strength(&game), 0);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_status(&StatusType::SHACKLED), 0);
+}
+
+#[test]
+fn test_dark_shackles_blocked_by_artifact_gives_nothing_back() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::ARTIFACT, 1);
+    
+    play_on(&mut game, dark_shackles(100, false), ENEMY);
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    
+    assert_eq!(enemy_strength(&game), 0);
+}
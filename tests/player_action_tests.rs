@@ -0,0 +1,71 @@
+use SlayTheSpire::core::{EnemyId, EntityId, PlayerAction};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::cards::{defend, strike, whirlwind};
+
+mod common;
+use common::create_game;
+
+#[test]
+fn test_legal_actions_list_each_target() {
+    let mut game = create_game(&["dragonling", "dragonling"], 11);
+    game.player_mut().refill_energy();
+    game.add_card_to_hand(strike(1, false));
+    game.add_card_to_hand(defend(2, false));
+    
+    let actions = game.legal_actions();
+    
+    assert!(actions.contains(&PlayerAction::PlayCard { hand_index: 0, target: Some(EntityId::Enemy(EnemyId(0))) }));
+    assert!(actions.contains(&PlayerAction::PlayCard { hand_index: 0, target: Some(EntityId::Enemy(EnemyId(1))) }));
+    assert!(actions.contains(&PlayerAction::PlayCard { hand_index: 1, target: None }));
+    assert!(actions.contains(&PlayerAction::UseHeroAbility));
+    assert_eq!(actions.last(), Some(&PlayerAction::EndTurn));
+    assert_eq!(actions.len(), 5);
+}
+
+#[test]
+fn test_legal_actions_respect_energy() {
+    let mut game = create_game(&["dragonling"], 11);
+    let energy = game.player().get_energy();
+    game.player_mut().spend_energy(energy);
+    game.add_card_to_hand(strike(1, false));
+    game.add_card_to_hand(whirlwind(2, false));
+    
+    let actions = game.legal_actions();
+    
+    // With no energy only the X-cost card and ending the turn remain.
+    assert_eq!(actions, vec![
+        PlayerAction::PlayCard { hand_index: 1, target: None },
+        PlayerAction::EndTurn,
+    ]);
+}
+
+#[test]
+fn test_validate_matches_play_card() {
+    let mut game = create_game(&["dragonling"], 11);
+    game.player_mut().refill_energy();
+    game.add_card_to_hand(strike(1, false));
+    
+    let untargeted = PlayerAction::PlayCard { hand_index: 0, target: None };
+    let out_of_bounds = PlayerAction::PlayCard { hand_index: 3, target: None };
+    let missing_enemy = PlayerAction::PlayCard { hand_index: 0, target: Some(EntityId::Enemy(EnemyId(9))) };
+    
+    assert!(game.validate_action(&untargeted).is_err());
+    assert!(game.validate_action(&out_of_bounds).is_err());
+    assert!(game.validate_action(&missing_enemy).is_err());
+    assert!(game.apply(untargeted).is_err());
+    assert_eq!(game.hand().len(), 1);
+    assert_eq!(game.player().get_energy(), 3);
+}
+
+#[test]
+fn test_apply_end_turn_runs_enemy_turn_and_starts_next() {
+    let mut game = create_game(&["dragonling"], 11);
+    game.start_player_turn();
+    let turn = game.get_turn_count();
+    
+    game.apply(PlayerAction::EndTurn).unwrap();
+    
+    assert_eq!(game.get_turn_count(), turn + 1);
+    assert!(game.player().get_current_health() < 100);
+    assert_eq!(game.player().get_energy(), game.player().get_max_energy());
+}
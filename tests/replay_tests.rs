@@ -0,0 +1,87 @@
+use std::fs;
+
+use SlayTheSpire::core::{EnemyId, EntityId, GameEvent, LogRecord, PlayerAction};
+use SlayTheSpire::cards::{starting_deck, strike};
+use SlayTheSpire::save::{default_registry, Replay};
+use SlayTheSpire::sim::{GreedyPolicy, PlayerPolicy};
+
+mod common;
+use common::{create_game_with_player, player};
+
+/// Regenerate with `UPDATE_GOLDEN=1 cargo test --test replay_tests` after an
+/// intended gameplay change, and review the diff.
+const GOLDEN_PATH: &str = "tests/golden/greedy_vs_two_dragonlings.replay.json";
+
+fn record_greedy_combat() -> Replay {
+    let mut game = create_game_with_player(player(80), &["dragonling", "dragonling"], starting_deck(), 2024);
+    game.start_combat();
+    game.start_recording();
+    
+    let mut policy = GreedyPolicy;
+    for _ in 0..500 {
+        if game.is_combat_over() {
+            break;
+        }
+        let action = policy.decide(&game);
+        game.apply(action).unwrap();
+    }
+    assert!(game.is_combat_over());
+    
+    Replay::from_recording(game.recording().unwrap())
+}
+
+#[test]
+fn test_replay_matches_golden_file() {
+    let json = record_greedy_combat().to_json().unwrap();
+    
+    if std::env::var("UPDATE_GOLDEN").is_ok() {
+        fs::write(GOLDEN_PATH, &json).unwrap();
+    }
+    
+    let expected = fs::read_to_string(GOLDEN_PATH)
+        .expect("golden file missing, run with UPDATE_GOLDEN=1 to create it");
+    assert_eq!(json, expected);
+}
+
+#[test]
+fn test_golden_replay_reexecutes_exactly() {
+    let replay = Replay::read(GOLDEN_PATH).unwrap();
+    
+    assert!(!replay.inputs().is_empty());
+    replay.verify(&default_registry()).unwrap();
+}
+
+#[test]
+fn test_log_records_input_with_before_and_after() {
+    let mut game = create_game_with_player(player(80), &["dragonling", "dragonling"], starting_deck(), 2024);
+    game.start_player_turn();
+    game.add_card_to_hand(strike(99, false));
+    game.start_recording();
+    
+    let hand_index = game.hand().len() - 1;
+    let target = EntityId::Enemy(EnemyId(0));
+    game.apply(PlayerAction::PlayCard { hand_index, target: Some(target) }).unwrap();
+    
+    let entries = game.recording().unwrap().log.entries();
+    assert_eq!(entries[0].record, LogRecord::Input(PlayerAction::PlayCard { hand_index, target: Some(target) }));
+    
+    let change = entries[0].changes.iter().find(|c| c.entity == target).unwrap();
+    let before = change.before.as_ref().unwrap().health;
+    let after = change.after.as_ref().unwrap().health;
+    assert_eq!(before - after, 6);
+    
+    assert!(entries.iter().any(|entry| matches!(
+        entry.record,
+        LogRecord::Event(GameEvent::DamageDealt { target: t, .. }) if t == target
+    )));
+    assert!(entries.iter().any(|entry| matches!(entry.record, LogRecord::Event(GameEvent::CardPlayed { card: 99, .. }))));
+}
+
+#[test]
+fn test_tampered_replay_is_detected() {
+    let mut replay = record_greedy_combat();
+    let last = replay.log.len() - 1;
+    replay.log[last].turn += 1;
+    
+    assert!(replay.verify(&default_registry()).is_err());
+}
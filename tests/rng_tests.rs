@@ -0,0 +1,68 @@
+use SlayTheSpire::core::{GameState, Player, STSClass, Enemy, GameRng, RngStream};
+use SlayTheSpire::enemies::DataEnemy;
+use SlayTheSpire::cards::{strike, defend, inflame, haste};
+use rand::Rng;
+
+fn seeded_game(seed: u64) -> GameState {
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let enemies = vec![Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>];
+    let deck = (0..10)
+        .map(|i| match i % 4 {
+            0 => strike(i, false),
+            1 => defend(i, false),
+            2 => inflame(i, false),
+            _ => haste(i, false),
+        })
+        .collect();
+    GameState::new_with_rng(player, enemies, deck, GameRng::new(seed))
+}
+
+fn draw_order(game: &GameState) -> Vec<u32> {
+    game.draw_pile().iter().map(|c| c.instance_id()).collect()
+}
+
+#[test]
+fn test_same_seed_same_shuffle() {
+    let mut game_a = seeded_game(42);
+    let mut game_b = seeded_game(42);
+    
+    assert_eq!(game_a.seed(), 42);
+    assert_eq!(draw_order(&game_a), draw_order(&game_b));
+    
+    game_a.start_player_turn();
+    game_b.start_player_turn();
+    game_a.end_player_turn();
+    game_b.end_player_turn();
+    game_a.execute_all_enemy_turns();
+    game_b.execute_all_enemy_turns();
+    game_a.start_player_turn();
+    game_b.start_player_turn();
+    
+    let hand_a: Vec<u32> = game_a.hand().iter().map(|c| c.instance_id()).collect();
+    let hand_b: Vec<u32> = game_b.hand().iter().map(|c| c.instance_id()).collect();
+    assert_eq!(hand_a, hand_b);
+}
+
+#[test]
+fn test_different_seeds_differ() {
+    let orders: Vec<Vec<u32>> = (0..5).map(|seed| draw_order(&seeded_game(seed))).collect();
+    assert!(orders.iter().any(|order| order != &orders[0]));
+}
+
+#[test]
+fn test_streams_are_independent() {
+    let mut untouched = GameRng::new(7);
+    let mut used = GameRng::new(7);
+    
+    for _ in 0..10 {
+        let _: u32 = used.stream(RngStream::CardRewards).r#gen();
+    }
+    
+    let a: u64 = untouched.stream(RngStream::Shuffle).r#gen();
+    let b: u64 = used.stream(RngStream::Shuffle).r#gen();
+    assert_eq!(a, b);
+    
+    let shuffle: u64 = GameRng::new(7).stream(RngStream::Shuffle).r#gen();
+    let rewards: u64 = GameRng::new(7).stream(RngStream::CardRewards).r#gen();
+    assert_ne!(shuffle, rewards);
+}
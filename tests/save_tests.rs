@@ -0,0 +1,125 @@
+use SlayTheSpire::core::{GameState, Player, STSClass, EntityId, EnemyId, StatusType, Enemy, GameRng};
+use SlayTheSpire::enemies::DataEnemy;
+use SlayTheSpire::cards::{strike, defend, inflame, quick_strike, barricade};
+use SlayTheSpire::save::{default_registry, CombatSave, COMBAT_SAVE_VERSION, RunSave, RUN_SAVE_VERSION};
+
+fn mid_turn_game() -> GameState {
+    let player = Player::new(STSClass::Ironclad, "TestHero".to_string(), 100);
+    let enemies = vec![
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+        Box::new(DataEnemy::new("dragonling")) as Box<dyn Enemy>,
+    ];
+    let deck = vec![
+        strike(1, false),
+        strike(2, false),
+        defend(3, false),
+        defend(4, false),
+        inflame(5, false),
+        quick_strike(6, false),
+        barricade(7, false),
+        strike(8, true),
+    ];
+    let mut game = GameState::new_with_rng(player, enemies, deck, GameRng::new(1234));
+    
+    game.start_player_turn();
+    game.add_card_to_hand(quick_strike(20, false));
+    let last = game.hand().len() - 1;
+    game.play_card(last, Some(EntityId::Enemy(EnemyId(1)))).unwrap();
+    game.add_status(EntityId::Enemy(EnemyId(0)), StatusType::VULNERABLE, 2);
+    game.use_hero_ability().unwrap();
+    game
+}
+
+fn to_json(game: &GameState) -> String {
+    CombatSave::new(game).to_json().unwrap()
+}
+
+#[test]
+fn test_snapshot_round_trip_is_exact() {
+    let game = mid_turn_game();
+    let json = to_json(&game);
+    
+    let restored = CombatSave::from_json(&json).unwrap().restore(&default_registry()).unwrap();
+    
+    assert_eq!(to_json(&restored), json);
+    assert_eq!(restored.hand().len(), game.hand().len());
+    assert_eq!(restored.player().get_energy(), game.player().get_energy());
+    assert_eq!(restored.enemy(EnemyId(0)).unwrap().get_status(&StatusType::VULNERABLE), 2);
+    assert_eq!(restored.seed(), 1234);
+}
+
+#[test]
+fn test_restored_combat_continues_identically() {
+    let mut original = mid_turn_game();
+    let json = to_json(&original);
+    let mut restored = CombatSave::from_json(&json).unwrap().restore(&default_registry()).unwrap();
+    
+    for game in [&mut original, &mut restored] {
+        game.end_player_turn();
+        game.execute_all_enemy_turns();
+        game.start_player_turn();
+    }
+    
+    assert_eq!(to_json(&original), to_json(&restored));
+    assert!(restored.hand().iter().any(|c| c.is_upgraded() && c.instance_id() == original.exhaust_pile()[0].instance_id()));
+    assert_eq!(restored.player().get_energy(), original.player().get_energy());
+}
+
+#[test]
+fn test_rejects_unknown_version() {
+    let game = mid_turn_game();
+    let json = to_json(&game).replacen(
+        &format!("\"version\": {}", COMBAT_SAVE_VERSION),
+        "\"version\": 999",
+        1,
+    );
+    
+    assert!(CombatSave::from_json(&json).is_err());
+}
+
+#[test]
+fn test_run_save_round_trip_with_combat() {
+    let game = mid_turn_game();
+    let deck = vec![strike(1, false), defend(2, true)];
+    let run_save = RunSave::new(game.player(), &deck, 3, 2, game.rng()).with_combat(game.snapshot());
+    
+    let loaded = RunSave::from_json(&run_save.to_json().unwrap()).unwrap();
+    let registry = default_registry();
+    
+    assert_eq!(loaded.version, RUN_SAVE_VERSION);
+    assert_eq!(loaded.floor, 3);
+    assert_eq!(loaded.current_node_index, 2);
+    let restored_deck = loaded.restore_deck(&registry);
+    assert_eq!(restored_deck.len(), 2);
+    assert!(restored_deck[1].is_upgraded());
+    
+    let combat = GameState::from_snapshot(loaded.combat.unwrap(), &registry).unwrap();
+    assert_eq!(to_json(&combat), to_json(&game));
+}
+
+#[test]
+fn test_run_save_drops_removed_cards() {
+    let game = mid_turn_game();
+    let deck = vec![strike(1, false), defend(2, false)];
+    let json = RunSave::new(game.player(), &deck, 1, 0, game.rng())
+        .to_json()
+        .unwrap()
+        .replace("\"defend\"", "\"retired_card\"");
+    
+    let loaded = RunSave::from_json(&json).unwrap();
+    let restored_deck = loaded.restore_deck(&default_registry());
+    
+    assert_eq!(restored_deck.len(), 1);
+    assert_eq!(restored_deck[0].id(), "strike");
+}
+
+#[test]
+fn test_run_save_rejects_future_version() {
+    let game = mid_turn_game();
+    let json = RunSave::new(game.player(), &[], 1, 0, game.rng())
+        .to_json()
+        .unwrap()
+        .replacen(&format!("\"version\": {}", RUN_SAVE_VERSION), "\"version\": 999", 1);
+    
+    assert!(RunSave::from_json(&json).is_err());
+}
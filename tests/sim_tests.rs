@@ -0,0 +1,60 @@
+use SlayTheSpire::save::default_registry;
+use SlayTheSpire::sim::{self, GreedyPolicy, PlayerPolicy, RandomPolicy, SimulationConfig};
+
+fn starter_config() -> SimulationConfig {
+    SimulationConfig {
+        deck: sim::parse_deck_list("strike,strike,strike,defend,defend,inflame,whirlwind,quick_strike"),
+        encounter: vec!["dragonling".to_string()],
+        seed: 42,
+        combats: 20,
+        ..SimulationConfig::default()
+    }
+}
+
+#[test]
+fn test_parse_deck_list() {
+    assert_eq!(
+        sim::parse_deck_list(" strike, defend+ ,,whirlwind"),
+        vec!["strike", "defend+", "whirlwind"]
+    );
+}
+
+#[test]
+fn test_greedy_policy_beats_single_dragonling() {
+    let registry = default_registry();
+    let report = sim::run_simulation(&registry, &starter_config(), |_| {
+        Box::new(GreedyPolicy) as Box<dyn PlayerPolicy>
+    }).unwrap();
+    
+    assert_eq!(report.combats, 20);
+    assert!(report.win_rate() > 0.5, "win rate was {}", report.win_rate());
+    assert!(report.average_turns() >= 1.0);
+    assert!(report.damage_by_card.get("strike").copied().unwrap_or(0) > 0);
+}
+
+#[test]
+fn test_simulation_is_deterministic_for_a_seed() {
+    let registry = default_registry();
+    let config = starter_config();
+    let run = || sim::run_simulation(&registry, &config, |seed| {
+        Box::new(RandomPolicy::new(seed)) as Box<dyn PlayerPolicy>
+    }).unwrap();
+    
+    let first = run();
+    let second = run();
+    
+    assert_eq!(first.wins, second.wins);
+    assert_eq!(first.total_hp_lost, second.total_hp_lost);
+    assert_eq!(first.total_turns, second.total_turns);
+    assert_eq!(first.damage_by_card, second.damage_by_card);
+}
+
+#[test]
+fn test_unknown_ids_are_reported() {
+    let registry = default_registry();
+    let mut config = starter_config();
+    config.encounter = vec!["not_an_enemy".to_string()];
+    
+    let result = sim::run_combat(&registry, &config, 1, &mut GreedyPolicy);
+    assert!(result.is_err());
+}
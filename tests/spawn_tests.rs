@@ -0,0 +1,115 @@
+use SlayTheSpire::core::{DamageKind, EnemyId, EntityId, GameEvent, GameState, LogRecord};
+use SlayTheSpire::core::game_state::MAX_ENEMIES;
+use SlayTheSpire::save::default_registry;
+
+mod common;
+use common::{create_game, enemy};
+
+fn events(game: &GameState) -> Vec<GameEvent> {
+    game.recording()
+        .unwrap()
+        .log
+        .entries()
+        .iter()
+        .filter_map(|entry| match &entry.record {
+            LogRecord::Event(event) => Some(event.clone()),
+            LogRecord::Input(_) => None,
+        })
+        .collect()
+}
+
+fn slots(game: &GameState) -> Vec<(EnemyId, usize)> {
+    game.enemy_ids().into_iter().map(|id| (id, game.enemy_slot(id).unwrap())).collect()
+}
+
+fn kill(game: &mut GameState, enemy_id: EnemyId) {
+    game.apply_damage(DamageKind::HpLoss, EntityId::Player, EntityId::Enemy(enemy_id), 999);
+}
+
+#[test]
+fn test_summoned_enemy_is_ready_to_act() {
+    let mut game = create_game(&["cultist"], 2);
+    game.start_recording();
+    game.start_combat();
+    
+    let louse = game.summon_enemy(enemy("red_louse"), None).unwrap();
+    
+    assert_eq!(louse, EnemyId(1));
+    assert_eq!(game.enemy_slot(louse), Some(1));
+    assert!(game.enemy_intent(louse).is_some());
+    assert!(game.effects().any(|(owner, effect)| owner == EntityId::Enemy(louse) && effect.id() == "curl_up"));
+    assert!(events(&game).contains(&GameEvent::EnemySpawned { enemy: EntityId::Enemy(louse) }));
+}
+
+#[test]
+fn test_summons_fill_gaps_and_respect_the_roster_limit() {
+    let mut game = create_game(&["cultist", "cultist", "cultist"], 2);
+    game.start_recording();
+    game.start_combat();
+    kill(&mut game, EnemyId(1));
+    game.remove_dead_enemies();
+    
+    assert!(game.summon_enemy(enemy("jaw_worm"), Some(0)).is_err());
+    let filler = game.summon_enemy(enemy("jaw_worm"), None).unwrap();
+    assert_eq!(game.enemy_slot(filler), Some(1));
+    assert_eq!(game.enemy_ids(), [EnemyId(0), filler, EnemyId(2)]);
+    
+    while game.living_enemy_count() < MAX_ENEMIES {
+        game.summon_enemy(enemy("jaw_worm"), None).unwrap();
+    }
+    assert!(game.summon_enemy(enemy("jaw_worm"), None).is_err());
+}
+
+#[test]
+fn test_enemies_keep_their_slots_when_others_die() {
+    let mut game = create_game(&["red_louse", "green_louse", "red_louse"], 2);
+    game.start_recording();
+    game.start_combat();
+    
+    kill(&mut game, EnemyId(1));
+    game.remove_dead_enemies();
+    
+    assert_eq!(slots(&game), [(EnemyId(0), 0), (EnemyId(2), 2)]);
+    assert_eq!(game.enemy_slot(EnemyId(1)), None);
+}
+
+#[test]
+fn test_split_takes_the_splitters_slot_and_the_nearest_free_one() {
+    let mut game = create_game(&["cultist", "slime_boss", "cultist"], 2);
+    game.start_recording();
+    game.start_combat();
+    
+    let spawned = game.split_enemy(EnemyId(1), vec![enemy("acid_slime_l"), enemy("spike_slime_l")]);
+    
+    assert_eq!(spawned, [EnemyId(3), EnemyId(4)]);
+    assert_eq!(slots(&game), [(EnemyId(0), 0), (EnemyId(3), 1), (EnemyId(2), 2), (EnemyId(4), 3)]);
+    assert!(spawned.iter().all(|id| game.enemy_intent(*id).is_some()));
+}
+
+#[test]
+fn test_split_stops_at_the_roster_limit() {
+    let mut game = create_game(&["cultist", "cultist", "acid_slime_l", "cultist", "cultist"], 2);
+    game.start_recording();
+    game.start_combat();
+    
+    let spawned = game.split_enemy(EnemyId(2), vec![enemy("acid_slime_m"), enemy("acid_slime_m")]);
+    
+    assert_eq!(spawned.len(), 1);
+    assert_eq!(game.enemy_slot(spawned[0]), Some(2));
+    assert_eq!(game.living_enemy_count(), MAX_ENEMIES);
+}
+
+#[test]
+fn test_slots_survive_a_save() {
+    let mut game = create_game(&["cultist", "cultist"], 2);
+    game.start_recording();
+    game.start_combat();
+    kill(&mut game, EnemyId(0));
+    game.remove_dead_enemies();
+    game.summon_enemy(enemy("red_louse"), Some(3)).unwrap();
+    
+    let restored = GameState::from_snapshot(game.snapshot(), &default_registry()).unwrap();
+    
+    assert_eq!(slots(&restored), [(EnemyId(1), 1), (EnemyId(2), 3)]);
+    assert_eq!(restored.enemy(EnemyId(2)).unwrap().get_name(), "Red Louse");
+}
@@ -0,0 +1,173 @@
+use SlayTheSpire::cards::strike;
+use SlayTheSpire::core::{DecayTiming, EnemyId, EntityId, GameState, PlayerAction};
+use SlayTheSpire::core::{Stacking, StatusDefinition, StatusKind, StatusRegistry, StatusType};
+use SlayTheSpire::core::base_state::State;
+use SlayTheSpire::core::damage::DamageOperation;
+use SlayTheSpire::save::default_registry;
+
+mod common;
+use common::{create_game, ENEMY};
+
+fn start_game() -> GameState {
+    let mut game = create_game(&["dragonling"], 1);
+    game.start_player_turn();
+    game
+}
+
+fn enemy_status(game: &GameState, status: StatusType) -> i32 {
+    game.enemy(EnemyId(0)).unwrap().get_status(&status)
+}
+
+#[test]
+fn test_builtin_registry_describes_every_status() {
+    let registry = StatusRegistry::builtin();
+    assert_eq!(registry.definitions().count(), 13);
+    
+    let vulnerable = registry.get(&StatusType::VULNERABLE).unwrap();
+    assert_eq!(vulnerable.kind, StatusKind::Debuff);
+    assert_eq!(vulnerable.stacking, Stacking::Duration);
+    assert_eq!(vulnerable.decay, DecayTiming::OwnerTurnEnd);
+    assert_eq!(vulnerable.describe(2), "Takes 50% more damage from attacks for 2 turns.");
+    
+    assert!(registry.get(&StatusType::STRENGTH).unwrap().allows_negative);
+    assert_eq!(registry.get(&StatusType::PLATED_ARMOR).unwrap().name, "Plated Armor");
+}
+
+#[test]
+fn test_debuffs_on_enemies_last_until_the_end_of_their_turn() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::WEAK, 1);
+    
+    game.end_player_turn();
+    assert_eq!(enemy_status(&game, StatusType::WEAK), 1);
+    
+    game.execute_all_enemy_turns();
+    assert_eq!(enemy_status(&game, StatusType::WEAK), 0);
+}
+
+#[test]
+fn test_artifact_negates_debuffs_but_not_buffs() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::ARTIFACT, 1);
+    
+    game.add_status(ENEMY, StatusType::STRENGTH, 2);
+    game.add_status(ENEMY, StatusType::VULNERABLE, 2);
+    assert_eq!(enemy_status(&game, StatusType::STRENGTH), 2);
+    assert_eq!(enemy_status(&game, StatusType::VULNERABLE), 0);
+    assert_eq!(enemy_status(&game, StatusType::ARTIFACT), 0);
+    
+    game.add_status(ENEMY, StatusType::VULNERABLE, 2);
+    assert_eq!(enemy_status(&game, StatusType::VULNERABLE), 2);
+}
+
+#[test]
+fn test_statuses_without_negatives_stop_at_zero() {
+    let mut game = start_game();
+    game.add_status(ENEMY, StatusType::VULNERABLE, 1);
+    game.add_status(ENEMY, StatusType::VULNERABLE, -5);
+    
+    assert!(game.enemy(EnemyId(0)).unwrap().get_all_statuses().is_empty());
+}
+
+#[test]
+fn test_metallicize_and_plated_armor_block_at_turn_end() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::METALLICIZE, 3);
+    game.add_status(ENEMY, StatusType::PLATED_ARMOR, 4);
+    
+    game.end_player_turn();
+    assert_eq!(game.player().get_block(), 3);
+    
+    game.execute_all_enemy_turns();
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 4);
+    
+    game.start_player_turn();
+    game.deal_damage(EntityId::Player, ENEMY, 4);
+    assert_eq!(enemy_status(&game, StatusType::PLATED_ARMOR), 4, "fully blocked hits keep the armor");
+    
+    game.deal_damage(EntityId::Player, ENEMY, 10);
+    assert_eq!(enemy_status(&game, StatusType::PLATED_ARMOR), 3);
+}
+
+#[test]
+fn test_intangible_caps_damage_through_the_enemy_turn() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::INTANGIBLE, 1);
+    let hp_before = game.player().get_current_health();
+    
+    game.end_player_turn();
+    game.execute_all_enemy_turns();
+    
+    assert_eq!(game.player().get_current_health(), hp_before - 1);
+    assert_eq!(game.player().get_status(&StatusType::INTANGIBLE), 0);
+}
+
+#[test]
+fn test_entangled_blocks_attacks_for_the_turn() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::ENTANGLED, 1);
+    game.add_card_to_hand(strike(100, false));
+    let attack = PlayerAction::PlayCard { hand_index: game.hand().len() - 1, target: Some(ENEMY) };
+    
+    assert!(game.validate_action(&attack).is_err());
+    
+    game.end_player_turn();
+    assert_eq!(game.player().get_status(&StatusType::ENTANGLED), 0);
+}
+
+#[test]
+fn test_custom_statuses_can_be_registered() {
+    let mut game = start_game();
+    let burning = StatusType::new("Burning");
+    let mut registry = StatusRegistry::builtin();
+    registry.register(StatusDefinition {
+        id: burning.clone(),
+        name: "Burning".to_string(),
+        kind: StatusKind::Debuff,
+        stacking: Stacking::Intensity,
+        decay: DecayTiming::OpponentTurnEnd,
+        allows_negative: false,
+        description: "Burns for {}.".to_string(),
+        damage_modifier: None,
+        decays_into: None,
+        block_modifier: None,
+    });
+    game.set_status_registry(registry);
+    
+    game.add_status(ENEMY, burning.clone(), 3);
+    assert_eq!(enemy_status(&game, burning.clone()), 3);
+    let mut game = GameState::from_snapshot(game.snapshot(), &default_registry()).unwrap();
+    assert!(game.status_definition(&burning).is_some());
+    
+    game.end_player_turn();
+    assert_eq!(enemy_status(&game, burning), 0);
+}
+
+#[test]
+fn test_block_modifiers_come_from_status_definitions() {
+    let mut game = start_game();
+    game.add_status(EntityId::Player, StatusType::FRAIL, 1);
+    game.add_status(EntityId::Player, StatusType::DEXTERITY, 3);
+    game.gain_block(EntityId::Player, 5);
+    assert_eq!(game.player().get_block(), 6);
+    
+    let fortified = StatusType::new("Fortified");
+    let mut registry = StatusRegistry::builtin();
+    registry.register(StatusDefinition {
+        id: fortified.clone(),
+        name: "Fortified".to_string(),
+        kind: StatusKind::Buff,
+        stacking: Stacking::Duration,
+        decay: DecayTiming::OwnerTurnEnd,
+        allows_negative: false,
+        description: "Gains double Block for {} turns.".to_string(),
+        damage_modifier: None,
+        decays_into: None,
+        block_modifier: Some(DamageOperation::Multiply(2.0)),
+    });
+    game.set_status_registry(registry);
+    
+    game.add_status(ENEMY, fortified, 1);
+    game.gain_block(ENEMY, 5);
+    assert_eq!(game.enemy(EnemyId(0)).unwrap().get_block(), 10);
+}